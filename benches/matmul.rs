@@ -0,0 +1,54 @@
+//! Times a 1024x1024 f32 matmul through the CPU backend against a naive
+//! triple loop. Run with `cargo bench --bench matmul`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use kranium::{CpuBackend, Tensor};
+
+const N: usize = 1024;
+
+/// The straightforward kernel: each output element is a dot product over a
+/// strided column of `b`.
+fn naive(a: &[f32], b: &[f32], n: usize) -> Vec<f32> {
+    let mut out = vec![0.0; n * n];
+    for i in 0..n {
+        for j in 0..n {
+            let mut acc = 0.0;
+            for p in 0..n {
+                acc += a[i * n + p] * b[p * n + j];
+            }
+            out[i * n + j] = acc;
+        }
+    }
+    out
+}
+
+fn best_of<R>(runs: usize, mut f: impl FnMut() -> R) -> (Duration, R) {
+    let mut best = Duration::MAX;
+    let mut result = None;
+    for _ in 0..runs {
+        let start = Instant::now();
+        let r = black_box(f());
+        best = best.min(start.elapsed());
+        result = Some(r);
+    }
+    (best, result.unwrap())
+}
+
+fn main() {
+    let a: Vec<f32> = (0..N * N).map(|i| (i % 17) as f32 * 0.25).collect();
+    let b: Vec<f32> = (0..N * N).map(|i| (i % 13) as f32 * 0.5).collect();
+    let ta = Tensor::from_data(a.clone(), &[N, N], CpuBackend::new());
+    let tb = Tensor::from_data(b.clone(), &[N, N], CpuBackend::new());
+
+    let (naive_time, expected) = best_of(1, || naive(&a, &b, N));
+    let (tiled_time, product) = best_of(3, || ta.matmul(&tb));
+    assert_eq!(product.data(), expected.as_slice(), "results differ");
+
+    println!("matmul {N}x{N}: naive {naive_time:?}, CpuBackend {tiled_time:?}");
+    println!(
+        "speedup: {:.1}x",
+        naive_time.as_secs_f64() / tiled_time.as_secs_f64()
+    );
+}
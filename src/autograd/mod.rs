@@ -0,0 +1,5 @@
+//! Reverse-mode automatic differentiation on top of [`Tensor`](crate::Tensor).
+
+mod variable;
+
+pub use variable::Variable;
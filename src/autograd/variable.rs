@@ -0,0 +1,274 @@
+use std::cell::{Ref, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+use crate::tensor::Tensor;
+use crate::tensor::backend::Backend;
+use crate::tensor::numeric::Numeric;
+
+/// Maps the gradient of a node's output to the gradients of its parents, in
+/// the same order as `Node::parents`.
+type BackwardFn<T, B> = Box<dyn Fn(&Tensor<T, B>) -> Vec<Tensor<T, B>>>;
+
+struct Node<T, B> {
+    value: Tensor<T, B>,
+    grad: Option<Tensor<T, B>>,
+    parents: Vec<Variable<T, B>>,
+    backward: Option<BackwardFn<T, B>>,
+}
+
+/// A tensor that records the operations applied to it so gradients can be
+/// propagated back with [`Variable::backward`].
+///
+/// Cloning a `Variable` is cheap and yields a handle to the same graph node.
+pub struct Variable<T, B> {
+    node: Rc<RefCell<Node<T, B>>>,
+}
+
+impl<T, B> Clone for Variable<T, B> {
+    fn clone(&self) -> Self {
+        Self {
+            node: Rc::clone(&self.node),
+        }
+    }
+}
+
+impl<T, B> Variable<T, B> {
+    /// Identifies the graph node behind this handle.
+    fn key(&self) -> usize {
+        Rc::as_ptr(&self.node) as usize
+    }
+}
+
+impl<T: Numeric, B: Backend<T> + 'static> Variable<T, B> {
+    /// Wraps `value` as a leaf of the computation graph.
+    pub fn new(value: Tensor<T, B>) -> Self {
+        Self::from_node(value, Vec::new(), None)
+    }
+
+    fn from_node(
+        value: Tensor<T, B>,
+        parents: Vec<Variable<T, B>>,
+        backward: Option<BackwardFn<T, B>>,
+    ) -> Self {
+        Self {
+            node: Rc::new(RefCell::new(Node {
+                value,
+                grad: None,
+                parents,
+                backward,
+            })),
+        }
+    }
+
+    pub fn value(&self) -> Ref<'_, Tensor<T, B>> {
+        Ref::map(self.node.borrow(), |n| &n.value)
+    }
+
+    /// Accumulated gradient of a leaf, populated by [`Variable::backward`].
+    /// Intermediate nodes never hold one.
+    pub fn grad(&self) -> Option<Tensor<T, B>> {
+        self.node.borrow().grad.clone()
+    }
+
+    pub fn zero_grad(&self) {
+        self.node.borrow_mut().grad = None;
+    }
+
+    /// Element-wise sum. Unlike the tensor operators this doesn't
+    /// broadcast, since the gradients would come back in the broadcast shape.
+    pub fn add(&self, other: &Self) -> Self {
+        self.value().assert_same_shape(&other.value());
+        let value = &*self.value() + &*other.value();
+        Self::from_node(
+            value,
+            vec![self.clone(), other.clone()],
+            Some(Box::new(|g| vec![g.clone(), g.clone()])),
+        )
+    }
+
+    /// Element-wise product; the shapes must match, as for [`Variable::add`].
+    pub fn mul(&self, other: &Self) -> Self {
+        let (a, b) = (self.value().clone(), other.value().clone());
+        a.assert_same_shape(&b);
+        let value = &a * &b;
+        Self::from_node(
+            value,
+            vec![self.clone(), other.clone()],
+            Some(Box::new(move |g| vec![g * &b, g * &a])),
+        )
+    }
+
+    pub fn matmul(&self, other: &Self) -> Self {
+        let (a, b) = (self.value().clone(), other.value().clone());
+        let value = a.matmul(&b);
+        Self::from_node(
+            value,
+            vec![self.clone(), other.clone()],
+            Some(Box::new(move |g| {
+                vec![g.matmul(&b.transpose()), a.transpose().matmul(g)]
+            })),
+        )
+    }
+
+    /// Back-propagates from this variable, which must hold a single element,
+    /// accumulating into the `grad` of every leaf it depends on.
+    ///
+    /// Gradients of intermediate nodes only live for the duration of the
+    /// call, so calling `backward` again, on this graph or one sharing
+    /// intermediates with it, adds exactly one more pass to the leaves.
+    pub fn backward(&self) {
+        let seed = {
+            let value = self.value();
+            assert_eq!(
+                value.numel(),
+                1,
+                "backward() needs a scalar output, got shape {:?}",
+                value.shape()
+            );
+            Tensor::ones(value.shape(), value.backend().clone())
+        };
+        let mut grads = HashMap::from([(self.key(), seed)]);
+
+        for var in self.topological_order().into_iter().rev() {
+            let Some(grad) = grads.remove(&var.key()) else {
+                continue;
+            };
+            let node = var.node.borrow();
+            let Some(backward) = &node.backward else {
+                drop(node);
+                var.accumulate(grad);
+                continue;
+            };
+            for (parent, g) in node.parents.iter().zip(backward(&grad)) {
+                let sum = match grads.remove(&parent.key()) {
+                    Some(existing) => &existing + &g,
+                    None => g,
+                };
+                grads.insert(parent.key(), sum);
+            }
+        }
+    }
+
+    fn accumulate(&self, g: Tensor<T, B>) {
+        let mut node = self.node.borrow_mut();
+        node.grad = Some(match node.grad.take() {
+            Some(existing) => &existing + &g,
+            None => g,
+        });
+    }
+
+    /// Nodes reachable from `self`, each listed after all of its parents.
+    fn topological_order(&self) -> Vec<Self> {
+        fn visit<T, B>(
+            var: &Variable<T, B>,
+            seen: &mut HashSet<usize>,
+            order: &mut Vec<Variable<T, B>>,
+        ) {
+            if !seen.insert(var.key()) {
+                return;
+            }
+            for parent in &var.node.borrow().parents {
+                visit(parent, seen, order);
+            }
+            order.push(var.clone());
+        }
+
+        let mut order = Vec::new();
+        visit(self, &mut HashSet::new(), &mut order);
+        order
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::backend::CpuBackend;
+
+    fn scalar(x: f64) -> Variable<f64, CpuBackend> {
+        Variable::new(Tensor::from_data(vec![x], &[1], CpuBackend::new()))
+    }
+
+    #[test]
+    fn gradients_of_a_times_b_plus_a() {
+        let a = scalar(3.0);
+        let b = scalar(4.0);
+        let y = a.mul(&b).add(&a);
+        assert_eq!(y.value().data(), &[15.0]);
+
+        y.backward();
+        // dy/da = b + 1, dy/db = a
+        assert_eq!(a.grad().unwrap().data(), &[5.0]);
+        assert_eq!(b.grad().unwrap().data(), &[3.0]);
+    }
+
+    #[test]
+    fn matmul_gradients() {
+        let x = Variable::new(Tensor::from_data(
+            vec![1.0, 2.0],
+            &[1, 2],
+            CpuBackend::new(),
+        ));
+        let w = Variable::new(Tensor::from_data(
+            vec![3.0, 4.0],
+            &[2, 1],
+            CpuBackend::new(),
+        ));
+        let y = x.matmul(&w);
+        y.backward();
+
+        assert_eq!(y.value().data(), &[11.0]);
+        let dx = x.grad().unwrap();
+        let dw = w.grad().unwrap();
+        assert_eq!(dx.shape(), &[1, 2]);
+        assert_eq!(dx.data(), &[3.0, 4.0]);
+        assert_eq!(dw.shape(), &[2, 1]);
+        assert_eq!(dw.data(), &[1.0, 2.0]);
+    }
+
+    #[test]
+    fn repeated_backward_adds_one_pass_per_call() {
+        let a = scalar(3.0);
+        let b = scalar(4.0);
+        let y = a.mul(&b).add(&a);
+        y.backward();
+        y.backward();
+        assert_eq!(a.grad().unwrap().data(), &[10.0]);
+        assert_eq!(b.grad().unwrap().data(), &[6.0]);
+
+        // Two outputs sharing the intermediate z = a * b.
+        a.zero_grad();
+        b.zero_grad();
+        let z = a.mul(&b);
+        z.add(&a).backward();
+        z.mul(&z).backward();
+        // (b + 1) + 2zb and a + 2za
+        assert_eq!(a.grad().unwrap().data(), &[101.0]);
+        assert_eq!(b.grad().unwrap().data(), &[75.0]);
+        assert!(z.grad().is_none());
+    }
+
+    #[test]
+    fn zero_grad_clears_accumulated_gradient() {
+        let a = scalar(2.0);
+        a.mul(&a).backward();
+        assert_eq!(a.grad().unwrap().data(), &[4.0]);
+        a.zero_grad();
+        assert!(a.grad().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "shape mismatch")]
+    fn add_rejects_broadcasting() {
+        let a = Variable::new(Tensor::<f64, _>::zeros(&[2, 2], CpuBackend::new()));
+        let b = Variable::new(Tensor::<f64, _>::zeros(&[2], CpuBackend::new()));
+        a.add(&b);
+    }
+
+    #[test]
+    #[should_panic(expected = "scalar output")]
+    fn backward_requires_scalar() {
+        let v = Variable::new(Tensor::<f64, _>::zeros(&[2], CpuBackend::new()));
+        v.backward();
+    }
+}
@@ -0,0 +1,219 @@
+use crate::tensor::backend::Backend;
+use crate::tensor::numeric::Numeric;
+use crate::tensor::{Rng, Tensor};
+
+/// Groups `(input, label)` samples into batches, stacking each along a new
+/// leading axis: a batch of `n` inputs shaped `[c, h]` is one `[n, c, h]`
+/// tensor.
+///
+/// Every input must share a shape, and so must every label. Iterate one
+/// epoch at a time with [`DataLoader::epoch`] or `for batch in &mut loader`.
+#[derive(Debug, Clone)]
+pub struct DataLoader<T, B> {
+    samples: Vec<(Tensor<T, B>, Tensor<T, B>)>,
+    batch_size: usize,
+    rng: Option<Rng>,
+    drop_last: bool,
+}
+
+impl<T: Numeric, B: Backend<T>> DataLoader<T, B> {
+    /// Batches `samples` in order, keeping a final short batch.
+    pub fn new(samples: Vec<(Tensor<T, B>, Tensor<T, B>)>, batch_size: usize) -> Self {
+        assert!(batch_size > 0, "batch size must be positive");
+        if let Some((x0, y0)) = samples.first() {
+            for (i, (x, y)) in samples.iter().enumerate() {
+                assert!(
+                    x.shape() == x0.shape() && y.shape() == y0.shape(),
+                    "sample {i} has shapes {:?} / {:?}, expected {:?} / {:?}",
+                    x.shape(),
+                    y.shape(),
+                    x0.shape(),
+                    y0.shape()
+                );
+            }
+        }
+        Self {
+            samples,
+            batch_size,
+            rng: None,
+            drop_last: false,
+        }
+    }
+
+    /// Visits the samples in a fresh random order every epoch, drawn from a
+    /// generator seeded with `seed`.
+    pub fn shuffle(mut self, seed: u64) -> Self {
+        self.rng = Some(Rng::seed_from_u64(seed));
+        self
+    }
+
+    /// Whether to skip the final batch when it would be smaller than
+    /// `batch_size`.
+    pub fn drop_last(mut self, drop_last: bool) -> Self {
+        self.drop_last = drop_last;
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Batches per epoch.
+    pub fn num_batches(&self) -> usize {
+        if self.drop_last {
+            self.samples.len() / self.batch_size
+        } else {
+            self.samples.len().div_ceil(self.batch_size)
+        }
+    }
+
+    /// The batches of one epoch, reshuffling first if shuffling is on.
+    pub fn epoch(&mut self) -> Batches<'_, T, B> {
+        let mut order: Vec<usize> = (0..self.samples.len()).collect();
+        if let Some(rng) = &mut self.rng {
+            rng.shuffle(&mut order);
+        }
+        order.truncate(self.num_batches() * self.batch_size);
+        Batches {
+            loader: self,
+            order,
+            next: 0,
+        }
+    }
+}
+
+impl<'a, T: Numeric, B: Backend<T>> IntoIterator for &'a mut DataLoader<T, B> {
+    type Item = (Tensor<T, B>, Tensor<T, B>);
+    type IntoIter = Batches<'a, T, B>;
+
+    fn into_iter(self) -> Batches<'a, T, B> {
+        self.epoch()
+    }
+}
+
+/// One epoch of `(inputs, labels)` batches from a [`DataLoader`].
+#[derive(Debug)]
+pub struct Batches<'a, T, B> {
+    loader: &'a DataLoader<T, B>,
+    order: Vec<usize>,
+    next: usize,
+}
+
+impl<T: Numeric, B: Backend<T>> Iterator for Batches<'_, T, B> {
+    type Item = (Tensor<T, B>, Tensor<T, B>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.order.len() {
+            return None;
+        }
+        let end = self.order.len().min(self.next + self.loader.batch_size);
+        let picked = &self.order[self.next..end];
+        self.next = end;
+        let samples = &self.loader.samples;
+        Some((
+            stack(picked.iter().map(|&i| &samples[i].0)),
+            stack(picked.iter().map(|&i| &samples[i].1)),
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = (self.order.len() - self.next).div_ceil(self.loader.batch_size);
+        (left, Some(left))
+    }
+}
+
+impl<T: Numeric, B: Backend<T>> ExactSizeIterator for Batches<'_, T, B> {}
+
+/// Stacks same-shaped tensors along a new leading axis.
+fn stack<'a, T: Numeric, B: Backend<T> + 'a>(
+    tensors: impl ExactSizeIterator<Item = &'a Tensor<T, B>>,
+) -> Tensor<T, B> {
+    let count = tensors.len();
+    let mut tensors = tensors.peekable();
+    let first = *tensors.peek().expect("cannot stack an empty batch");
+    let (backend, shape) = (first.backend().clone(), first.shape().to_vec());
+    let mut data = Vec::with_capacity(count * first.numel());
+    for t in tensors {
+        data.extend_from_slice(&t.values());
+    }
+    let mut batch_shape = vec![count];
+    batch_shape.extend(shape);
+    Tensor::from_data(data, &batch_shape, backend)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::backend::CpuBackend;
+
+    /// Ten samples: input `[i, i]`, label `[i]`.
+    fn loader() -> DataLoader<f32, CpuBackend> {
+        let samples = (0..10)
+            .map(|i| {
+                let i = i as f32;
+                (
+                    Tensor::from_data(vec![i, i], &[2], CpuBackend::new()),
+                    Tensor::from_data(vec![i], &[1], CpuBackend::new()),
+                )
+            })
+            .collect();
+        DataLoader::new(samples, 4)
+    }
+
+    #[test]
+    fn drop_last_yields_only_full_batches() {
+        let mut loader = loader().drop_last(true);
+        assert_eq!(loader.num_batches(), 2);
+        let batches: Vec<_> = loader.epoch().collect();
+        assert_eq!(batches.len(), 2);
+        for (x, y) in &batches {
+            assert_eq!(x.shape(), &[4, 2]);
+            assert_eq!(y.shape(), &[4, 1]);
+        }
+        assert_eq!(batches[1].1.data(), &[4.0, 5.0, 6.0, 7.0]);
+    }
+
+    #[test]
+    fn keeps_the_short_last_batch_by_default() {
+        let mut loader = loader();
+        let sizes: Vec<usize> = (&mut loader)
+            .into_iter()
+            .map(|(x, _)| x.shape()[0])
+            .collect();
+        assert_eq!(sizes, [4, 4, 2]);
+        assert_eq!(loader.epoch().len(), 3);
+    }
+
+    #[test]
+    fn shuffles_reproducibly_per_epoch() {
+        let labels = |loader: &mut DataLoader<f32, CpuBackend>| -> Vec<f32> {
+            loader.epoch().flat_map(|(_, y)| y.to_vec()).collect()
+        };
+        let mut a = loader().shuffle(3);
+        let mut b = loader().shuffle(3);
+        let first = labels(&mut a);
+        assert_eq!(first, labels(&mut b));
+        assert_ne!(first, (0..10).map(|i| i as f32).collect::<Vec<_>>());
+        assert_ne!(labels(&mut a), first);
+
+        let mut sorted = first.clone();
+        sorted.sort_by(f32::total_cmp);
+        assert_eq!(sorted, (0..10).map(|i| i as f32).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic(expected = "sample 1 has shapes [3] / [1]")]
+    fn rejects_mismatched_samples() {
+        let sample = |n: usize| {
+            (
+                Tensor::<f32, _>::zeros(&[n], CpuBackend::new()),
+                Tensor::zeros(&[1], CpuBackend::new()),
+            )
+        };
+        DataLoader::new(vec![sample(2), sample(3)], 2);
+    }
+}
@@ -0,0 +1,5 @@
+//! Feeding training data: batching `(input, label)` samples into tensors.
+
+mod loader;
+
+pub use loader::{Batches, DataLoader};
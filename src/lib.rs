@@ -1,14 +1,13 @@
-pub fn add(left: u64, right: u64) -> u64 {
-    left + right
-}
+//! Kranium: a small n-dimensional tensor library with pluggable compute backends.
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub mod autograd;
+pub mod data;
+pub mod optim;
+pub mod tensor;
 
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
-}
+pub use tensor::backend::{Backend, CpuBackend, PooledCpuBackend};
+pub use tensor::error::TensorError;
+pub use tensor::numeric::{CastFrom, Float, Numeric, Pod, Signed};
+pub use tensor::{
+    Accumulator, Complex, FanMode, Layout, Rng, RollingPercentile, Tensor, TensorView, loss, where_,
+};
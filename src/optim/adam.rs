@@ -0,0 +1,109 @@
+use super::optimizer::{Optimizer, check_grads, zero_state};
+use crate::tensor::Tensor;
+use crate::tensor::backend::Backend;
+
+/// Adam: per-element step sizes from running estimates of the gradient's
+/// first and second moments, bias-corrected for their zero start.
+#[derive(Debug, Clone)]
+pub struct Adam<B> {
+    lr: f32,
+    betas: (f32, f32),
+    eps: f32,
+    steps: usize,
+    m: Vec<Tensor<f32, B>>,
+    v: Vec<Tensor<f32, B>>,
+}
+
+impl<B> Adam<B> {
+    /// Adam with the usual defaults, `betas = (0.9, 0.999)` and `eps = 1e-8`.
+    pub fn new(lr: f32) -> Self {
+        Self {
+            lr,
+            betas: (0.9, 0.999),
+            eps: 1e-8,
+            steps: 0,
+            m: Vec::new(),
+            v: Vec::new(),
+        }
+    }
+
+    /// Decay rates of the first and second moment estimates.
+    pub fn betas(mut self, beta1: f32, beta2: f32) -> Self {
+        for beta in [beta1, beta2] {
+            assert!(
+                (0.0..1.0).contains(&beta),
+                "Adam betas must be in [0, 1), got {beta}"
+            );
+        }
+        self.betas = (beta1, beta2);
+        self
+    }
+
+    /// Added to the denominator to keep updates finite.
+    pub fn eps(mut self, eps: f32) -> Self {
+        self.eps = eps;
+        self
+    }
+
+    /// Steps taken so far.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+}
+
+impl<B: Backend<f32>> Optimizer<B> for Adam<B> {
+    fn step(&mut self, params: &mut [Tensor<f32, B>], grads: &[Tensor<f32, B>]) {
+        check_grads(params, grads);
+        zero_state(&mut self.m, params);
+        zero_state(&mut self.v, params);
+        self.steps += 1;
+        let (beta1, beta2) = self.betas;
+        let correction1 = 1.0 - beta1.powf(self.steps as f32);
+        let correction2 = 1.0 - beta2.powf(self.steps as f32);
+        let (lr, eps) = (self.lr, self.eps);
+
+        let state = self.m.iter_mut().zip(&mut self.v);
+        for ((p, g), (m, v)) in params.iter_mut().zip(grads).zip(state) {
+            *m = m.map(|x| x * beta1).scaled_add(g, 1.0 - beta1);
+            *v = v.map(|x| x * beta2).scaled_add(&(g * g), 1.0 - beta2);
+            let update = m.zip_map(v, |m, v| {
+                (m / correction1) / ((v / correction2).sqrt() + eps)
+            });
+            *p = p.scaled_add(&update, -lr);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optim::optimizer::tests::minimize_quadratic;
+    use crate::tensor::backend::CpuBackend;
+
+    #[test]
+    fn first_step_moves_by_lr() {
+        // With bias correction the first update is lr * g / |g|.
+        let mut adam = Adam::new(0.1);
+        let mut params = [Tensor::from_data(vec![1.0, 1.0], &[2], CpuBackend::new())];
+        let grads = [Tensor::from_data(vec![5.0, -0.01], &[2], CpuBackend::new())];
+        adam.step(&mut params, &grads);
+        assert_eq!(adam.steps(), 1);
+        assert!((params[0].data()[0] - 0.9).abs() < 1e-6);
+        assert!((params[0].data()[1] - 1.1).abs() < 1e-5);
+    }
+
+    #[test]
+    fn converges_on_a_quadratic() {
+        let mut adam = Adam::new(0.1);
+        let p = minimize_quadratic(&mut adam, &[3.0, -1.0, 0.5], 500);
+        assert_eq!(adam.steps(), 500);
+        assert!(
+            p.data()
+                .iter()
+                .zip([3.0, -1.0, 0.5])
+                .all(|(x, t)| (x - t).abs() < 1e-2),
+            "{:?}",
+            p.data()
+        );
+    }
+}
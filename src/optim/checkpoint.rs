@@ -0,0 +1,145 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::tensor::Tensor;
+use crate::tensor::numeric::Numeric;
+
+/// Named tensors making up the state of a model or optimizer, kept sorted by
+/// name so saved files are deterministic.
+#[derive(Debug, Clone)]
+pub struct StateDict<T, B> {
+    entries: BTreeMap<String, Tensor<T, B>>,
+}
+
+impl<T, B> Default for StateDict<T, B> {
+    fn default() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T: Numeric, B> StateDict<T, B> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds or replaces the tensor stored under `name`.
+    pub fn insert(&mut self, name: impl Into<String>, tensor: Tensor<T, B>) {
+        self.entries.insert(name.into(), tensor);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Tensor<T, B>> {
+        self.entries.get(name)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Entries in name order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Tensor<T, B>)> {
+        self.entries.iter().map(|(k, v)| (k.as_str(), v))
+    }
+
+    /// Writes one line per entry: the name, the shape, then every element in
+    /// logical order, separated by spaces.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut out = io::BufWriter::new(fs::File::create(path)?);
+        for (name, tensor) in self.iter() {
+            write!(out, "{name} {:?}", tensor.shape())?;
+            for x in tensor.values().iter() {
+                write!(out, " {x:?}")?;
+            }
+            writeln!(out)?;
+        }
+        out.flush()
+    }
+}
+
+/// Saves a [`StateDict`] into `dir` every `every_n` steps, as
+/// `checkpoint-{step}.txt`.
+#[derive(Debug, Clone)]
+pub struct Checkpointer {
+    dir: PathBuf,
+    every_n: usize,
+}
+
+impl Checkpointer {
+    pub fn new(dir: impl Into<PathBuf>, every_n: usize) -> Self {
+        assert!(every_n > 0, "checkpoint interval must be positive");
+        Self {
+            dir: dir.into(),
+            every_n,
+        }
+    }
+
+    /// Path the checkpoint for `step` is written to.
+    pub fn path_for(&self, step: usize) -> PathBuf {
+        self.dir.join(format!("checkpoint-{step}.txt"))
+    }
+
+    /// Saves `state` if `step` is a multiple of the interval, creating the
+    /// directory on first use. Returns whether a checkpoint was written.
+    pub fn maybe_save<T: Numeric, B>(
+        &mut self,
+        step: usize,
+        state: &StateDict<T, B>,
+    ) -> io::Result<bool> {
+        if !step.is_multiple_of(self.every_n) {
+            return Ok(false);
+        }
+        fs::create_dir_all(&self.dir)?;
+        state.save(self.path_for(step))?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::backend::CpuBackend;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("kranium-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn saves_only_on_multiples_of_the_interval() {
+        let dir = scratch_dir("checkpoints");
+        let mut state = StateDict::new();
+        state.insert(
+            "w",
+            Tensor::from_data(vec![1.5f32, -2.0], &[2], CpuBackend::new()),
+        );
+
+        let mut ckpt = Checkpointer::new(&dir, 3);
+        let saved: Vec<usize> = (1..=10)
+            .filter(|&step| ckpt.maybe_save(step, &state).unwrap())
+            .collect();
+        assert_eq!(saved, [3, 6, 9]);
+
+        let mut files: Vec<String> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().into_string().unwrap())
+            .collect();
+        files.sort();
+        assert_eq!(
+            files,
+            ["checkpoint-3.txt", "checkpoint-6.txt", "checkpoint-9.txt"]
+        );
+        assert_eq!(
+            fs::read_to_string(ckpt.path_for(6)).unwrap(),
+            "w [2] 1.5 -2.0\n"
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
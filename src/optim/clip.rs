@@ -0,0 +1,57 @@
+use crate::tensor::Tensor;
+use crate::tensor::backend::Backend;
+
+/// Rescales `grads` in place so their global L2 norm, taken over every
+/// element of every tensor, is at most `max_norm`.
+///
+/// Gradients already within the limit are left untouched. Returns the norm
+/// before clipping, which is worth logging to spot exploding gradients.
+pub fn clip_grad_norm<B: Backend<f32>>(grads: &mut [Tensor<f32, B>], max_norm: f32) -> f32 {
+    assert!(
+        max_norm >= 0.0,
+        "clip_grad_norm needs a non-negative max_norm, got {max_norm}"
+    );
+    let squares = grads
+        .iter()
+        .map(|g| g.fold(0.0, |acc, x| x.mul_add(x, acc)))
+        .sum::<f32>();
+    let total_norm = squares.sqrt();
+    if total_norm > max_norm {
+        let scale = max_norm / total_norm;
+        for g in grads.iter_mut() {
+            *g = g.map(|x| x * scale);
+        }
+    }
+    total_norm
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::backend::CpuBackend;
+
+    fn grads() -> Vec<Tensor<f32, CpuBackend>> {
+        // Global norm sqrt(9 + 16 + 144) = 13.
+        vec![
+            Tensor::from_data(vec![3.0, 4.0], &[2], CpuBackend::new()),
+            Tensor::from_data(vec![12.0], &[1, 1], CpuBackend::new()),
+        ]
+    }
+
+    #[test]
+    fn scales_down_gradients_over_the_limit() {
+        let mut g = grads();
+        assert_eq!(clip_grad_norm(&mut g, 6.5), 13.0);
+        assert_eq!(g[0].data(), &[1.5, 2.0]);
+        assert_eq!(g[1].data(), &[6.0]);
+        assert_eq!(g[1].shape(), &[1, 1]);
+        assert!((clip_grad_norm(&mut g, 100.0) - 6.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn leaves_gradients_under_the_limit() {
+        let mut g = grads();
+        assert_eq!(clip_grad_norm(&mut g, 20.0), 13.0);
+        assert_eq!(g, grads());
+    }
+}
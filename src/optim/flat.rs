@@ -0,0 +1,74 @@
+use crate::tensor::Tensor;
+use crate::tensor::backend::Backend;
+use crate::tensor::numeric::Numeric;
+
+/// Concatenates the elements of every parameter, in order, into one 1D
+/// tensor on the backend of the first.
+pub fn flatten_params<T: Numeric, B: Backend<T>>(params: &[Tensor<T, B>]) -> Tensor<T, B> {
+    let first = params
+        .first()
+        .expect("flatten_params needs at least one parameter");
+    let mut data = Vec::with_capacity(params.iter().map(Tensor::numel).sum());
+    for p in params {
+        data.extend_from_slice(&p.values());
+    }
+    let len = data.len();
+    Tensor::from_data(data, &[len], first.backend().clone())
+}
+
+/// Scatters a vector produced by [`flatten_params`] back into `params`,
+/// keeping each parameter's shape.
+pub fn unflatten_params<T: Numeric, B: Backend<T>>(
+    flat: &Tensor<T, B>,
+    params: &mut [Tensor<T, B>],
+) {
+    let total: usize = params.iter().map(Tensor::numel).sum();
+    assert_eq!(
+        flat.numel(),
+        total,
+        "flat vector has {} elements but the parameters need {total}",
+        flat.numel()
+    );
+    let values = flat.values();
+    let mut offset = 0;
+    for p in params {
+        let n = p.numel();
+        let data = values[offset..offset + n].to_vec();
+        *p = Tensor::from_data(data, p.shape(), p.backend().clone());
+        offset += n;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::backend::CpuBackend;
+
+    #[test]
+    fn flatten_and_restore_round_trip() {
+        let w = Tensor::from_data(
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+            &[2, 3],
+            CpuBackend::new(),
+        );
+        let b = Tensor::from_data(vec![7.0, 8.0], &[2], CpuBackend::new());
+        let flat = flatten_params(&[w.clone(), b.clone()]);
+        assert_eq!(flat.shape(), &[8]);
+        assert_eq!(flat.data(), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+
+        let mut params = [
+            Tensor::zeros(&[2, 3], CpuBackend::new()),
+            Tensor::zeros(&[2], CpuBackend::new()),
+        ];
+        unflatten_params(&flat, &mut params);
+        assert_eq!(params, [w, b]);
+    }
+
+    #[test]
+    #[should_panic(expected = "parameters need 3")]
+    fn unflatten_rejects_wrong_length() {
+        let flat: Tensor<f32, _> = Tensor::zeros(&[4], CpuBackend::new());
+        let mut params = [Tensor::zeros(&[3], CpuBackend::new())];
+        unflatten_params(&flat, &mut params);
+    }
+}
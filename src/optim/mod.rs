@@ -0,0 +1,15 @@
+//! Optimization utilities. Parameters are plain [`Tensor`](crate::Tensor)s.
+
+mod adam;
+mod checkpoint;
+mod clip;
+mod flat;
+mod optimizer;
+mod sgd;
+
+pub use adam::Adam;
+pub use checkpoint::{Checkpointer, StateDict};
+pub use clip::clip_grad_norm;
+pub use flat::{flatten_params, unflatten_params};
+pub use optimizer::Optimizer;
+pub use sgd::Sgd;
@@ -0,0 +1,73 @@
+use crate::tensor::Tensor;
+use crate::tensor::backend::Backend;
+
+/// Updates parameters in place from their gradients, keeping whatever
+/// per-parameter state the method needs between steps.
+///
+/// `params` and `grads` pair up by position, and must be passed in the same
+/// order on every step so the state lines up with its parameter.
+pub trait Optimizer<B> {
+    fn step(&mut self, params: &mut [Tensor<f32, B>], grads: &[Tensor<f32, B>]);
+}
+
+/// Panics unless every parameter has a gradient of its shape.
+pub(crate) fn check_grads<B>(params: &[Tensor<f32, B>], grads: &[Tensor<f32, B>]) {
+    assert_eq!(
+        params.len(),
+        grads.len(),
+        "got {} gradients for {} parameters",
+        grads.len(),
+        params.len()
+    );
+    for (i, (p, g)) in params.iter().zip(grads).enumerate() {
+        assert_eq!(
+            p.shape(),
+            g.shape(),
+            "gradient {i} has shape {:?}, parameter has {:?}",
+            g.shape(),
+            p.shape()
+        );
+    }
+}
+
+/// Per-parameter state tensors, created as zeros the first time they're
+/// needed and checked against `params` afterwards.
+pub(crate) fn zero_state<B: Backend<f32>>(
+    state: &mut Vec<Tensor<f32, B>>,
+    params: &[Tensor<f32, B>],
+) {
+    if state.is_empty() {
+        *state = params.iter().map(Tensor::zeros_like).collect();
+    }
+    assert_eq!(
+        state.len(),
+        params.len(),
+        "optimizer state is for {} parameters, got {}",
+        state.len(),
+        params.len()
+    );
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::Optimizer;
+    use crate::tensor::Tensor;
+    use crate::tensor::backend::CpuBackend;
+
+    /// Runs `steps` updates on `f(p) = sum((p - target)^2)` from zero and
+    /// returns the final parameter.
+    pub(crate) fn minimize_quadratic(
+        opt: &mut impl Optimizer<CpuBackend>,
+        target: &[f32],
+        steps: usize,
+    ) -> Tensor<f32, CpuBackend> {
+        let target = Tensor::from_data(target.to_vec(), &[target.len()], CpuBackend::new());
+        let mut params = [target.zeros_like()];
+        for _ in 0..steps {
+            let grad = (&params[0] - &target).map(|d| 2.0 * d);
+            opt.step(&mut params, &[grad]);
+        }
+        let [p] = params;
+        p
+    }
+}
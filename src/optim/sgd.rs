@@ -0,0 +1,78 @@
+use super::optimizer::{Optimizer, check_grads, zero_state};
+use crate::tensor::Tensor;
+use crate::tensor::backend::Backend;
+
+/// Stochastic gradient descent with optional (heavy-ball) momentum:
+/// `v = momentum * v + g`, then `p -= lr * v`.
+#[derive(Debug, Clone)]
+pub struct Sgd<B> {
+    lr: f32,
+    momentum: f32,
+    velocity: Vec<Tensor<f32, B>>,
+}
+
+impl<B> Sgd<B> {
+    /// Plain SGD when `momentum` is 0.
+    pub fn new(lr: f32, momentum: f32) -> Self {
+        assert!(
+            (0.0..1.0).contains(&momentum),
+            "momentum must be in [0, 1), got {momentum}"
+        );
+        Self {
+            lr,
+            momentum,
+            velocity: Vec::new(),
+        }
+    }
+}
+
+impl<B: Backend<f32>> Optimizer<B> for Sgd<B> {
+    fn step(&mut self, params: &mut [Tensor<f32, B>], grads: &[Tensor<f32, B>]) {
+        check_grads(params, grads);
+        if self.momentum == 0.0 {
+            for (p, g) in params.iter_mut().zip(grads) {
+                *p = p.scaled_add(g, -self.lr);
+            }
+            return;
+        }
+        zero_state(&mut self.velocity, params);
+        for ((p, g), v) in params.iter_mut().zip(grads).zip(&mut self.velocity) {
+            *v = g.scaled_add(v, self.momentum);
+            *p = p.scaled_add(v, -self.lr);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optim::optimizer::tests::minimize_quadratic;
+    use crate::tensor::backend::CpuBackend;
+
+    #[test]
+    fn plain_sgd_step() {
+        let mut sgd = Sgd::new(0.5, 0.0);
+        let mut params = [Tensor::from_data(vec![1.0, 2.0], &[2], CpuBackend::new())];
+        let grads = [Tensor::from_data(vec![2.0, -4.0], &[2], CpuBackend::new())];
+        sgd.step(&mut params, &grads);
+        assert_eq!(params[0].data(), &[0.0, 4.0]);
+    }
+
+    #[test]
+    fn momentum_converges_on_a_quadratic() {
+        let p = minimize_quadratic(&mut Sgd::new(0.05, 0.9), &[3.0, -1.0], 200);
+        assert!(
+            p.data()
+                .iter()
+                .zip([3.0, -1.0])
+                .all(|(x, t)| (x - t).abs() < 1e-3)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "got 1 gradients for 2 parameters")]
+    fn step_rejects_missing_gradients() {
+        let mut params = [Tensor::zeros_cpu(&[2]), Tensor::zeros_cpu(&[2])];
+        Sgd::new(0.1, 0.0).step(&mut params, &[Tensor::zeros_cpu(&[2])]);
+    }
+}
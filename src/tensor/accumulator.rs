@@ -0,0 +1,78 @@
+use crate::tensor::Tensor;
+use crate::tensor::backend::Backend;
+use crate::tensor::numeric::Numeric;
+
+/// Running element-wise sum of same-shaped tensors, e.g. gradients collected
+/// over several micro-batches.
+///
+/// The buffer is sized from the first tensor added.
+#[derive(Debug, Clone)]
+pub struct Accumulator<T, B> {
+    sum: Option<Tensor<T, B>>,
+}
+
+impl<T, B> Default for Accumulator<T, B> {
+    fn default() -> Self {
+        Self { sum: None }
+    }
+}
+
+impl<T: Numeric, B: Backend<T>> Accumulator<T, B> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `t` into the running sum. Panics if its shape differs from the
+    /// tensors added before it.
+    pub fn add(&mut self, t: &Tensor<T, B>) {
+        self.sum = Some(match self.sum.take() {
+            Some(sum) => &sum + t,
+            None => Tensor::from_data(t.values().into_owned(), t.shape(), t.backend().clone()),
+        });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sum.is_none()
+    }
+
+    /// Returns the sum so far and resets the accumulator.
+    ///
+    /// Panics if nothing has been added since the last `take`.
+    pub fn take(&mut self) -> Tensor<T, B> {
+        self.sum
+            .take()
+            .expect("take() called on an empty Accumulator")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::backend::CpuBackend;
+
+    #[test]
+    fn accumulates_and_resets() {
+        let mut acc = Accumulator::new();
+        for batch in 0..3 {
+            let base = batch as f32;
+            acc.add(&Tensor::from_data(
+                vec![base, base + 1.0, base + 2.0, base + 3.0],
+                &[2, 2],
+                CpuBackend::new(),
+            ));
+        }
+        let total = acc.take();
+        assert_eq!(total.shape(), &[2, 2]);
+        assert_eq!(total.data(), &[3.0, 6.0, 9.0, 12.0]);
+        assert!(acc.is_empty());
+
+        acc.add(&Tensor::ones(&[2, 2], CpuBackend::new()));
+        assert_eq!(acc.take().data(), &[1.0, 1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "empty Accumulator")]
+    fn take_on_empty_panics() {
+        Accumulator::<f32, CpuBackend>::new().take();
+    }
+}
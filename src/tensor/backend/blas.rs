@@ -0,0 +1,178 @@
+//! CBLAS-accelerated backend, enabled with the `blas` feature.
+//!
+//! Only `matmul` goes through BLAS (`cblas_sgemm` / `cblas_dgemm`); every
+//! other operation is delegated to [`CpuBackend`].
+
+use std::os::raw::c_int;
+
+use super::{Backend, CpuBackend};
+
+const CBLAS_ROW_MAJOR: c_int = 101;
+const CBLAS_NO_TRANS: c_int = 111;
+
+#[link(name = "cblas")]
+unsafe extern "C" {
+    fn cblas_sgemm(
+        order: c_int,
+        trans_a: c_int,
+        trans_b: c_int,
+        m: c_int,
+        n: c_int,
+        k: c_int,
+        alpha: f32,
+        a: *const f32,
+        lda: c_int,
+        b: *const f32,
+        ldb: c_int,
+        beta: f32,
+        c: *mut f32,
+        ldc: c_int,
+    );
+
+    fn cblas_dgemm(
+        order: c_int,
+        trans_a: c_int,
+        trans_b: c_int,
+        m: c_int,
+        n: c_int,
+        k: c_int,
+        alpha: f64,
+        a: *const f64,
+        lda: c_int,
+        b: *const f64,
+        ldb: c_int,
+        beta: f64,
+        c: *mut f64,
+        ldc: c_int,
+    );
+}
+
+/// Backend that hands matrix products to the system BLAS.
+#[derive(Debug, Clone, Copy)]
+pub struct BlasBackend;
+
+fn dim(value: usize) -> c_int {
+    c_int::try_from(value).expect("matrix dimension exceeds the BLAS integer range")
+}
+
+macro_rules! impl_blas_backend {
+    ($t:ty, $gemm:ident) => {
+        impl Backend<$t> for BlasBackend {
+            fn zeros(&self, shape: &[usize]) -> Vec<$t> {
+                CpuBackend::new().zeros(shape)
+            }
+
+            fn ones(&self, shape: &[usize]) -> Vec<$t> {
+                CpuBackend::new().ones(shape)
+            }
+
+            fn full(&self, shape: &[usize], value: $t) -> Vec<$t> {
+                CpuBackend::new().full(shape, value)
+            }
+
+            fn add(&self, a: &[$t], b: &[$t]) -> Vec<$t> {
+                CpuBackend::new().add(a, b)
+            }
+
+            fn sub(&self, a: &[$t], b: &[$t]) -> Vec<$t> {
+                CpuBackend::new().sub(a, b)
+            }
+
+            fn mul(&self, a: &[$t], b: &[$t]) -> Vec<$t> {
+                CpuBackend::new().mul(a, b)
+            }
+
+            fn div(&self, a: &[$t], b: &[$t]) -> Vec<$t> {
+                CpuBackend::new().div(a, b)
+            }
+
+            fn fma(&self, a: &[$t], b: &[$t], c: &[$t]) -> Vec<$t> {
+                CpuBackend::new().fma(a, b, c)
+            }
+
+            fn matmul(&self, a: &[$t], b: &[$t], m: usize, k: usize, n: usize) -> Vec<$t> {
+                assert_eq!(a.len(), m * k, "lhs buffer doesn't match [{m}, {k}]");
+                assert_eq!(b.len(), k * n, "rhs buffer doesn't match [{k}, {n}]");
+                let mut out = vec![0.0; m * n];
+                if out.is_empty() {
+                    return out;
+                }
+                // SAFETY: the buffer lengths were checked above, and for a
+                // row-major, non-transposed product the leading dimensions are
+                // the column counts k, n and n.
+                unsafe {
+                    $gemm(
+                        CBLAS_ROW_MAJOR,
+                        CBLAS_NO_TRANS,
+                        CBLAS_NO_TRANS,
+                        dim(m),
+                        dim(n),
+                        dim(k),
+                        1.0,
+                        a.as_ptr(),
+                        dim(k.max(1)),
+                        b.as_ptr(),
+                        dim(n),
+                        0.0,
+                        out.as_mut_ptr(),
+                        dim(n),
+                    );
+                }
+                out
+            }
+
+            fn map<F>(&self, a: &[$t], f: F) -> Vec<$t>
+            where
+                F: Fn($t) -> $t + Sync,
+            {
+                CpuBackend::new().map(a, f)
+            }
+
+            fn zip_map<F>(&self, a: &[$t], b: &[$t], f: F) -> Vec<$t>
+            where
+                F: Fn($t, $t) -> $t + Sync,
+            {
+                CpuBackend::new().zip_map(a, b, f)
+            }
+
+            fn fold<F>(&self, a: &[$t], identity: $t, f: F) -> $t
+            where
+                F: Fn($t, $t) -> $t + Sync,
+            {
+                CpuBackend::new().fold(a, identity, f)
+            }
+        }
+    };
+}
+
+impl_blas_backend!(f32, cblas_sgemm);
+impl_blas_backend!(f64, cblas_dgemm);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Tensor;
+
+    const A: [f64; 6] = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    const B: [f64; 6] = [7.0, 8.0, 9.0, 10.0, 11.0, 12.0];
+
+    #[test]
+    fn matmul_matches_cpu_backend() {
+        let expected = CpuBackend::new().matmul(&A, &B, 2, 3, 2);
+        assert_eq!(BlasBackend.matmul(&A, &B, 2, 3, 2), expected);
+
+        let a32: Vec<f32> = A.iter().map(|&x| x as f32).collect();
+        let b32: Vec<f32> = B.iter().map(|&x| x as f32).collect();
+        assert_eq!(
+            BlasBackend.matmul(&a32, &b32, 2, 3, 2),
+            CpuBackend::new().matmul(&a32, &b32, 2, 3, 2)
+        );
+    }
+
+    #[test]
+    fn tensor_matmul_through_blas() {
+        let a = Tensor::from_data(A.to_vec(), &[2, 3], BlasBackend);
+        let b = Tensor::from_data(B.to_vec(), &[3, 2], BlasBackend);
+        assert_eq!(a.matmul(&b).data(), &[58.0, 64.0, 139.0, 154.0]);
+    }
+}
@@ -0,0 +1,524 @@
+use std::any::TypeId;
+use std::collections::BTreeMap;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use super::Backend;
+use super::parallel::{par_chunks_with, par_fill, par_reduce, with_worker_limit};
+use crate::tensor::numeric::Numeric;
+
+/// CPU backend working on `Vec` buffers, spreading large element-wise
+/// workloads across threads.
+///
+/// [`CpuBackend::new`] uses every available core;
+/// [`CpuBackend::with_threads`] bounds how many threads its ops may use.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CpuBackend {
+    threads: Option<NonZeroUsize>,
+    /// Configurations found by [`CpuBackend::autotune_matmul`], keyed by
+    /// element type and [`size_bucket`]. Clones share it until one of them
+    /// tunes again.
+    tuning: Arc<BTreeMap<(TypeId, u32), MatmulConfig>>,
+}
+
+/// How the CPU backend blocks a matmul and schedules it across threads.
+///
+/// Every configuration computes each output element with the same sequence
+/// of operations, so the choice only affects speed, never the result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatmulConfig {
+    /// Output rows handed to a worker at a time; the height of a tile.
+    pub rows_per_task: usize,
+    /// Output columns computed together while their slice of the
+    /// transposed rhs stays in cache; the width of a tile.
+    pub tile: usize,
+    /// Smallest `m * k * n` for which worker threads are used.
+    pub parallel_threshold: usize,
+}
+
+impl Default for MatmulConfig {
+    fn default() -> Self {
+        Self {
+            rows_per_task: 64,
+            tile: 64,
+            parallel_threshold: 1 << 15,
+        }
+    }
+}
+
+/// Products whose `m * k * n` share a power of two count as similar.
+fn size_bucket(m: usize, k: usize, n: usize) -> u32 {
+    m.saturating_mul(k).saturating_mul(n).max(1).ilog2()
+}
+
+fn matmul_with<T: Numeric>(
+    a: &[T],
+    b: &[T],
+    dims: (usize, usize, usize),
+    config: MatmulConfig,
+) -> Vec<T> {
+    let mut out = vec![T::zero(); dims.0 * dims.2];
+    matmul_into(a, b, dims, config, &mut out);
+    out
+}
+
+/// [`matmul_with`] writing into `out`, which holds `m * n` elements.
+fn matmul_into<T: Numeric>(
+    a: &[T],
+    b: &[T],
+    (m, k, n): (usize, usize, usize),
+    config: MatmulConfig,
+    out: &mut [T],
+) {
+    if n == 0 {
+        return;
+    }
+    // With the rhs transposed, every output element is a dot product of two
+    // contiguous length-k rows, summed in increasing `p` from zero whatever
+    // the tiling.
+    let mut bt = vec![T::zero(); k * n];
+    for p in 0..k {
+        for j in 0..n {
+            bt[j * k + p] = b[p * n + j];
+        }
+    }
+    let parallel = m.saturating_mul(k).saturating_mul(n) >= config.parallel_threshold;
+    let (rows, tile) = (config.rows_per_task.max(1), config.tile.max(1));
+    par_chunks_with(out, rows * n, parallel, |task, block| {
+        let first_row = task * rows;
+        for j0 in (0..n).step_by(tile) {
+            let cols = j0..n.min(j0 + tile);
+            for (r, row) in block.chunks_mut(n).enumerate() {
+                let lhs = &a[(first_row + r) * k..][..k];
+                for j in cols.clone() {
+                    let rhs = &bt[j * k..][..k];
+                    let mut acc = T::zero();
+                    for p in 0..k {
+                        acc = acc + lhs[p] * rhs[p];
+                    }
+                    row[j] = acc;
+                }
+            }
+        }
+    });
+}
+
+impl CpuBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A backend whose ops run on at most `threads` threads, the calling one
+    /// included. Other backends, and the process as a whole, are unaffected.
+    pub fn with_threads(threads: usize) -> Self {
+        Self {
+            threads: Some(
+                NonZeroUsize::new(threads).expect("with_threads needs at least one thread"),
+            ),
+            ..Self::default()
+        }
+    }
+
+    /// The thread cap set by [`CpuBackend::with_threads`], if any.
+    pub fn threads(&self) -> Option<usize> {
+        self.threads.map(NonZeroUsize::get)
+    }
+
+    /// Runs `f` under this backend's thread cap.
+    fn install<R>(&self, f: impl FnOnce() -> R) -> R {
+        with_worker_limit(self.threads, f)
+    }
+
+    /// Times a few scheduling configurations on a `[m, k] @ [k, n]` sample
+    /// of `T` (`sample_shape = [m, k, n]`) and caches the fastest one for
+    /// later `T` matmuls of similar size on this backend.
+    ///
+    /// Only this backend and clones made from it afterwards use the result.
+    pub fn autotune_matmul<T: Numeric>(&mut self, sample_shape: [usize; 3]) -> MatmulConfig {
+        let [m, k, n] = sample_shape;
+        let a: Vec<T> = (0..m * k).map(|i| T::from((i % 13) as u8)).collect();
+        let b: Vec<T> = (0..k * n).map(|i| T::from((i % 7) as u8)).collect();
+
+        let mut best = (Duration::MAX, MatmulConfig::default());
+        let candidates = [0, usize::MAX].into_iter().flat_map(|parallel_threshold| {
+            [(4, 32), (16, 64), (64, 64), (64, 128)].into_iter().map(
+                move |(rows_per_task, tile)| MatmulConfig {
+                    rows_per_task,
+                    tile,
+                    parallel_threshold,
+                },
+            )
+        });
+        for config in candidates {
+            let elapsed = (0..2)
+                .map(|_| {
+                    let start = Instant::now();
+                    std::hint::black_box(self.install(|| matmul_with(&a, &b, (m, k, n), config)));
+                    start.elapsed()
+                })
+                .min()
+                .unwrap_or(Duration::MAX);
+            if elapsed < best.0 {
+                best = (elapsed, config);
+            }
+        }
+
+        Arc::make_mut(&mut self.tuning).insert((TypeId::of::<T>(), size_bucket(m, k, n)), best.1);
+        best.1
+    }
+
+    /// The tuned configuration for `T` at this size, if any, else the
+    /// default.
+    fn matmul_config<T: 'static>(&self, m: usize, k: usize, n: usize) -> MatmulConfig {
+        self.tuning
+            .get(&(TypeId::of::<T>(), size_bucket(m, k, n)))
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+impl<T: Numeric> Backend<T> for CpuBackend {
+    fn zeros(&self, shape: &[usize]) -> Vec<T> {
+        vec![T::zero(); shape.iter().product()]
+    }
+
+    fn ones(&self, shape: &[usize]) -> Vec<T> {
+        vec![T::one(); shape.iter().product()]
+    }
+
+    fn full(&self, shape: &[usize], value: T) -> Vec<T> {
+        let mut out = self.uninit(shape);
+        self.install(|| par_fill(&mut out, |_| value));
+        out
+    }
+
+    fn add(&self, a: &[T], b: &[T]) -> Vec<T> {
+        self.zip_map(a, b, |x, y| x + y)
+    }
+
+    fn sub(&self, a: &[T], b: &[T]) -> Vec<T> {
+        self.zip_map(a, b, |x, y| x - y)
+    }
+
+    fn mul(&self, a: &[T], b: &[T]) -> Vec<T> {
+        self.zip_map(a, b, |x, y| x * y)
+    }
+
+    fn div(&self, a: &[T], b: &[T]) -> Vec<T> {
+        self.zip_map(a, b, |x, y| x / y)
+    }
+
+    fn fma(&self, a: &[T], b: &[T], c: &[T]) -> Vec<T> {
+        assert!(
+            a.len() == b.len() && a.len() == c.len(),
+            "buffer length mismatch"
+        );
+        let mut out = vec![T::zero(); a.len()];
+        self.install(|| par_fill(&mut out, |i| a[i].mul_add(b[i], c[i])));
+        out
+    }
+
+    fn matmul(&self, a: &[T], b: &[T], m: usize, k: usize, n: usize) -> Vec<T> {
+        assert_eq!(a.len(), m * k, "lhs buffer doesn't match [{m}, {k}]");
+        assert_eq!(b.len(), k * n, "rhs buffer doesn't match [{k}, {n}]");
+        self.install(|| matmul_with(a, b, (m, k, n), self.matmul_config::<T>(m, k, n)))
+    }
+
+    /// A single product gets the parallel matmul; a real batch is split
+    /// across threads by matrix, each computed serially, so the two levels
+    /// never nest.
+    fn bmm(&self, a: &[T], b: &[T], batch: usize, m: usize, k: usize, n: usize) -> Vec<T> {
+        assert_eq!(
+            a.len(),
+            batch * m * k,
+            "lhs buffer doesn't match [{batch}, {m}, {k}]"
+        );
+        assert_eq!(
+            b.len(),
+            batch * k * n,
+            "rhs buffer doesn't match [{batch}, {k}, {n}]"
+        );
+        if batch == 1 {
+            return self.matmul(a, b, m, k, n);
+        }
+        let config = self.matmul_config::<T>(m, k, n);
+        let serial = MatmulConfig {
+            parallel_threshold: usize::MAX,
+            ..config
+        };
+        let work = batch.saturating_mul(m).saturating_mul(k).saturating_mul(n);
+        let mut out = vec![T::zero(); batch * m * n];
+        self.install(|| {
+            par_chunks_with(
+                &mut out,
+                m * n,
+                work >= config.parallel_threshold,
+                |i, chunk| {
+                    let lhs = &a[i * m * k..][..m * k];
+                    let rhs = &b[i * k * n..][..k * n];
+                    matmul_into(lhs, rhs, (m, k, n), serial, chunk);
+                },
+            );
+        });
+        out
+    }
+
+    fn map<F>(&self, a: &[T], f: F) -> Vec<T>
+    where
+        F: Fn(T) -> T + Sync,
+    {
+        let mut out = vec![T::zero(); a.len()];
+        self.install(|| par_fill(&mut out, |i| f(a[i])));
+        out
+    }
+
+    fn zip_map<F>(&self, a: &[T], b: &[T], f: F) -> Vec<T>
+    where
+        F: Fn(T, T) -> T + Sync,
+    {
+        assert_eq!(a.len(), b.len(), "buffer length mismatch");
+        let mut out = vec![T::zero(); a.len()];
+        self.install(|| par_fill(&mut out, |i| f(a[i], b[i])));
+        out
+    }
+
+    fn fold<F>(&self, a: &[T], identity: T, f: F) -> T
+    where
+        F: Fn(T, T) -> T + Sync,
+    {
+        self.install(|| par_reduce(a, identity, f))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn elementwise_ops() {
+        let a = [1.0, 2.0, 3.0];
+        let b = [4.0, 5.0, 6.0];
+        assert_eq!(CpuBackend::new().add(&a, &b), vec![5.0, 7.0, 9.0]);
+        assert_eq!(CpuBackend::new().sub(&a, &b), vec![-3.0, -3.0, -3.0]);
+        assert_eq!(CpuBackend::new().mul(&a, &b), vec![4.0, 10.0, 18.0]);
+        assert_eq!(CpuBackend::new().div(&b, &a), vec![4.0, 2.5, 2.0]);
+    }
+
+    #[test]
+    fn fma_uses_fused_rounding() {
+        // 0.1 * 10 rounds to exactly 1.0, so only the fused form keeps the
+        // representation error of 0.1.
+        let out = CpuBackend::new().fma(&[0.1f64], &[10.0], &[-1.0]);
+        assert_eq!(out, vec![0.1f64.mul_add(10.0, -1.0)]);
+        assert_ne!(out[0], 0.0);
+        assert_eq!(
+            CpuBackend::new().fma(&[2, 3], &[4, 5], &[1, 1]),
+            vec![9, 16]
+        );
+    }
+
+    #[test]
+    fn matmul_2x3_by_3x2() {
+        let a = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let b = [7.0, 8.0, 9.0, 10.0, 11.0, 12.0];
+        assert_eq!(
+            CpuBackend::new().matmul(&a, &b, 2, 3, 2),
+            vec![58.0, 64.0, 139.0, 154.0]
+        );
+    }
+
+    fn naive_matmul(a: &[i64], b: &[i64], m: usize, k: usize, n: usize) -> Vec<i64> {
+        let mut out = vec![0; m * n];
+        for i in 0..m {
+            for j in 0..n {
+                out[i * n + j] = (0..k).map(|p| a[i * k + p] * b[p * n + j]).sum();
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn matmul_is_correct_after_autotuning() {
+        let mut backend = CpuBackend::new();
+        let config = backend.autotune_matmul::<i64>([48, 40, 56]);
+        assert_eq!(backend.matmul_config::<i64>(48, 40, 56), config);
+
+        for (m, k, n) in [(48, 40, 56), (7, 130, 3)] {
+            let a: Vec<i64> = (0..m * k).map(|i| (i % 11) as i64 - 5).collect();
+            let b: Vec<i64> = (0..k * n).map(|i| (i % 9) as i64 - 4).collect();
+            assert_eq!(
+                backend.matmul(&a, &b, m, k, n),
+                naive_matmul(&a, &b, m, k, n)
+            );
+        }
+    }
+
+    #[test]
+    fn autotuning_stays_on_the_tuned_backend() {
+        let untouched = CpuBackend::new();
+        let mut tuned = CpuBackend::new();
+        let config = tuned.autotune_matmul::<f32>([48, 40, 56]);
+
+        assert_eq!(tuned.clone().matmul_config::<f32>(48, 40, 56), config);
+        assert_eq!(
+            tuned.matmul_config::<f64>(48, 40, 56),
+            MatmulConfig::default()
+        );
+        assert_eq!(
+            untouched.matmul_config::<f32>(48, 40, 56),
+            MatmulConfig::default()
+        );
+        assert_eq!(CpuBackend::new(), untouched);
+    }
+
+    #[test]
+    fn every_matmul_config_gives_the_same_result() {
+        let (m, k, n) = (33, 17, 29);
+        let a: Vec<f64> = (0..m * k).map(|i| (i as f64).sin()).collect();
+        let b: Vec<f64> = (0..k * n).map(|i| (i as f64).cos()).collect();
+        let reference = matmul_with(&a, &b, (m, k, n), MatmulConfig::default());
+        for (rows_per_task, tile) in [(1, 1), (3, 7), (64, 64)] {
+            for parallel_threshold in [0, usize::MAX] {
+                let config = MatmulConfig {
+                    rows_per_task,
+                    tile,
+                    parallel_threshold,
+                };
+                assert_eq!(matmul_with(&a, &b, (m, k, n), config), reference);
+            }
+        }
+    }
+
+    #[test]
+    fn large_buffers_match_serial_result() {
+        let a: Vec<i64> = (0..100_000).collect();
+        let out = CpuBackend::new().map(&a, |x| x * 2);
+        assert!(out.iter().enumerate().all(|(i, &x)| x == 2 * i as i64));
+    }
+
+    #[test]
+    fn bmm_matches_separate_matmuls() {
+        let (batch, m, k, n) = (8, 24, 40, 32);
+        let a: Vec<f32> = (0..batch * m * k).map(|i| (i % 17) as f32 * 0.25).collect();
+        let b: Vec<f32> = (0..batch * k * n).map(|i| (i % 9) as f32 - 4.0).collect();
+        let expected: Vec<f32> = (0..batch)
+            .flat_map(|i| {
+                CpuBackend::new().matmul(
+                    &a[i * m * k..][..m * k],
+                    &b[i * k * n..][..k * n],
+                    m,
+                    k,
+                    n,
+                )
+            })
+            .collect();
+        assert_eq!(CpuBackend::new().bmm(&a, &b, batch, m, k, n), expected);
+        assert_eq!(
+            CpuBackend::new().bmm(&a[..m * k], &b[..k * n], 1, m, k, n),
+            expected[..m * n]
+        );
+    }
+
+    #[test]
+    fn thread_limited_backend_matches_the_default() {
+        let (m, k, n) = (96, 80, 72);
+        let a: Vec<f64> = (0..m * k).map(|i| (i % 11) as f64 * 0.5).collect();
+        let b: Vec<f64> = (0..k * n).map(|i| (i % 5) as f64 - 2.0).collect();
+        let two = CpuBackend::with_threads(2);
+        assert_eq!(two.threads(), Some(2));
+        assert_eq!(
+            two.matmul(&a, &b, m, k, n),
+            CpuBackend::new().matmul(&a, &b, m, k, n)
+        );
+
+        let big: Vec<i64> = (0..100_000).collect();
+        assert_eq!(
+            two.map(&big, |x| x * 3),
+            CpuBackend::new().map(&big, |x| x * 3)
+        );
+    }
+
+    #[test]
+    fn single_thread_backend_stays_on_the_caller() {
+        use std::collections::HashSet;
+        use std::sync::Mutex;
+
+        let seen = Mutex::new(HashSet::new());
+        let a: Vec<i64> = (0..100_000).collect();
+        CpuBackend::with_threads(1).map(&a, |x| {
+            seen.lock().unwrap().insert(std::thread::current().id());
+            x
+        });
+        let seen = seen.into_inner().unwrap();
+        assert_eq!(seen, HashSet::from([std::thread::current().id()]));
+    }
+
+    #[test]
+    fn single_thread_backend_caps_batched_and_folded_ops() {
+        use std::collections::HashSet;
+        use std::ops::{Add, Div, Mul, Sub};
+        use std::sync::Mutex;
+        use std::thread::{self, ThreadId};
+
+        use crate::tensor::Tensor;
+
+        static SEEN: Mutex<Option<HashSet<ThreadId>>> = Mutex::new(None);
+
+        /// An `f64` that notes which thread does each multiplication.
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        struct Spy(f64);
+
+        impl From<u8> for Spy {
+            fn from(x: u8) -> Self {
+                Spy(f64::from(x))
+            }
+        }
+        impl Add for Spy {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                Spy(self.0 + rhs.0)
+            }
+        }
+        impl Sub for Spy {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {
+                Spy(self.0 - rhs.0)
+            }
+        }
+        impl Mul for Spy {
+            type Output = Self;
+            fn mul(self, rhs: Self) -> Self {
+                let mut seen = SEEN.lock().unwrap();
+                seen.get_or_insert_with(HashSet::new)
+                    .insert(thread::current().id());
+                Spy(self.0 * rhs.0)
+            }
+        }
+        impl Div for Spy {
+            type Output = Self;
+            fn div(self, rhs: Self) -> Self {
+                Spy(self.0 / rhs.0)
+            }
+        }
+        impl Numeric for Spy {}
+
+        let one = CpuBackend::with_threads(1);
+        let a = Tensor::full(&[8, 24, 40], Spy(0.5), one.clone());
+        let b = Tensor::full(&[8, 40, 32], Spy(2.0), one.clone());
+        let out = a.bmm_strided(&b);
+        assert_eq!(out.get(&[7, 23, 31]), Spy(40.0));
+
+        let big = Tensor::full(&[100_000], Spy(1.0), one);
+        assert_eq!(big.par_fold(Spy(1.0), |x, y| x * y), Spy(1.0));
+
+        let seen = SEEN.lock().unwrap().take().unwrap();
+        assert_eq!(seen, HashSet::from([thread::current().id()]));
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one thread")]
+    fn with_threads_rejects_zero() {
+        CpuBackend::with_threads(0);
+    }
+}
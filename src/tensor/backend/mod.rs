@@ -0,0 +1,171 @@
+#[cfg(feature = "blas")]
+mod blas;
+mod cpu;
+pub(crate) mod parallel;
+mod pooled;
+
+#[cfg(feature = "blas")]
+pub use blas::BlasBackend;
+pub use cpu::{CpuBackend, MatmulConfig};
+pub use pooled::PooledCpuBackend;
+
+use std::ops::Neg;
+
+use crate::tensor::numeric::{Float, Numeric, Signed};
+
+/// Storage and compute strategy for tensors holding elements of type `T`.
+///
+/// Backends operate on flat, row-major buffers; the tensor takes care of
+/// shapes and strides. Binary element-wise methods expect `a` and `b` to have
+/// the same length.
+pub trait Backend<T: Numeric>: Clone + Send + Sync {
+    fn zeros(&self, shape: &[usize]) -> Vec<T>;
+
+    fn ones(&self, shape: &[usize]) -> Vec<T>;
+
+    /// A buffer for `shape` with every element set to `value`.
+    fn full(&self, shape: &[usize], value: T) -> Vec<T> {
+        vec![value; shape.iter().product()]
+    }
+
+    /// A buffer for `shape` whose contents are unspecified, for callers that
+    /// overwrite every element before reading any.
+    ///
+    /// Backends may return whatever is cheapest, including stale values. The
+    /// default returns zeros: for primitive types that is a zeroed allocation
+    /// the OS hands out lazily, so it costs about the same as leaving the
+    /// memory untouched while never exposing uninitialized reads (which would
+    /// be undefined behavior even for `Copy` types).
+    fn uninit(&self, shape: &[usize]) -> Vec<T> {
+        self.zeros(shape)
+    }
+
+    fn add(&self, a: &[T], b: &[T]) -> Vec<T>;
+
+    fn sub(&self, a: &[T], b: &[T]) -> Vec<T>;
+
+    fn mul(&self, a: &[T], b: &[T]) -> Vec<T>;
+
+    fn div(&self, a: &[T], b: &[T]) -> Vec<T>;
+
+    /// `a * b + c` in a single pass. All three buffers have the same length.
+    fn fma(&self, a: &[T], b: &[T], c: &[T]) -> Vec<T>;
+
+    /// `a + scale * b`, computed in one pass with a fused multiply-add where
+    /// the element type supports it.
+    fn scaled_add(&self, a: &[T], b: &[T], scale: T) -> Vec<T> {
+        self.zip_map(a, b, |x, y| y.mul_add(scale, x))
+    }
+
+    /// Multiplies the row-major `[m, k]` matrix `a` by the `[k, n]` matrix `b`.
+    fn matmul(&self, a: &[T], b: &[T], m: usize, k: usize, n: usize) -> Vec<T>;
+
+    /// `batch` independent matmuls over back-to-back `[m, k]` and `[k, n]`
+    /// matrices, giving back-to-back `[m, n]` results.
+    ///
+    /// The default runs them one after another through [`Backend::matmul`].
+    fn bmm(&self, a: &[T], b: &[T], batch: usize, m: usize, k: usize, n: usize) -> Vec<T> {
+        let mut out = Vec::with_capacity(batch * m * n);
+        for i in 0..batch {
+            let lhs = &a[i * m * k..][..m * k];
+            let rhs = &b[i * k * n..][..k * n];
+            out.extend(self.matmul(lhs, rhs, m, k, n));
+        }
+        out
+    }
+
+    fn exp(&self, a: &[T]) -> Vec<T>
+    where
+        T: Float,
+    {
+        self.map(a, T::exp)
+    }
+
+    fn ln(&self, a: &[T]) -> Vec<T>
+    where
+        T: Float,
+    {
+        self.map(a, T::ln)
+    }
+
+    fn sqrt(&self, a: &[T]) -> Vec<T>
+    where
+        T: Float,
+    {
+        self.map(a, T::sqrt)
+    }
+
+    fn powf(&self, a: &[T], exponent: T) -> Vec<T>
+    where
+        T: Float,
+    {
+        self.map(a, |x| x.powf(exponent))
+    }
+
+    fn neg(&self, a: &[T]) -> Vec<T>
+    where
+        T: Neg<Output = T>,
+    {
+        self.map(a, |x| -x)
+    }
+
+    fn abs(&self, a: &[T]) -> Vec<T>
+    where
+        T: Signed,
+    {
+        self.map(a, T::abs)
+    }
+
+    /// Caps every element into `[min, max]`. NaN passes through unchanged.
+    fn clamp(&self, a: &[T], min: T, max: T) -> Vec<T>
+    where
+        T: PartialOrd,
+    {
+        self.map(a, |x| {
+            if x < min {
+                min
+            } else if x > max {
+                max
+            } else {
+                x
+            }
+        })
+    }
+
+    /// Raises every element below `min` to `min`. NaN passes through
+    /// unchanged.
+    fn clamp_min(&self, a: &[T], min: T) -> Vec<T>
+    where
+        T: PartialOrd,
+    {
+        self.map(a, |x| if x < min { min } else { x })
+    }
+
+    /// Lowers every element above `max` to `max`. NaN passes through
+    /// unchanged.
+    fn clamp_max(&self, a: &[T], max: T) -> Vec<T>
+    where
+        T: PartialOrd,
+    {
+        self.map(a, |x| if x > max { max } else { x })
+    }
+
+    /// Applies `f` to every element.
+    fn map<F>(&self, a: &[T], f: F) -> Vec<T>
+    where
+        F: Fn(T) -> T + Sync;
+
+    /// Combines `a` and `b` element by element with `f`.
+    fn zip_map<F>(&self, a: &[T], b: &[T], f: F) -> Vec<T>
+    where
+        F: Fn(T, T) -> T + Sync;
+
+    /// Folds `a` with the associative `f`, starting every partial fold from
+    /// `identity`, so backends may split the work in any grouping.
+    fn fold<F>(&self, a: &[T], identity: T, f: F) -> T
+    where
+        F: Fn(T, T) -> T + Sync,
+    {
+        a.iter().fold(identity, |acc, &x| f(acc, x))
+    }
+}
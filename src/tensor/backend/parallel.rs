@@ -0,0 +1,124 @@
+//! Minimal data-parallel helpers built on scoped threads.
+
+use std::cell::Cell;
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+use std::thread;
+
+/// Outputs smaller than this are filled on the calling thread; spawning
+/// workers costs more than it saves.
+const PARALLEL_THRESHOLD: usize = 1 << 15;
+
+thread_local! {
+    /// Cap on worker threads for helpers called from this thread, set by
+    /// [`with_worker_limit`].
+    static WORKER_LIMIT: Cell<Option<NonZeroUsize>> = const { Cell::new(None) };
+}
+
+fn worker_count() -> usize {
+    let available = thread::available_parallelism().map_or(1, |n| n.get());
+    WORKER_LIMIT
+        .get()
+        .map_or(available, |limit| available.min(limit.get()))
+}
+
+/// Runs `f` with every helper it calls on this thread using at most `limit`
+/// workers, or as many as there are cores when `limit` is `None`.
+pub(crate) fn with_worker_limit<R>(limit: Option<NonZeroUsize>, f: impl FnOnce() -> R) -> R {
+    let previous = WORKER_LIMIT.replace(limit);
+    // Restore on unwind too, so a panicking op doesn't leak its limit.
+    struct Restore(Option<NonZeroUsize>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            WORKER_LIMIT.set(self.0);
+        }
+    }
+    let _restore = Restore(previous);
+    f()
+}
+
+/// Splits `out` into chunks of `chunk_len` elements and calls
+/// `f(chunk_index, chunk)` on each, spreading the chunks over worker threads
+/// once `out` is large enough to be worth it.
+pub(crate) fn par_chunks<T, F>(out: &mut [T], chunk_len: usize, f: F)
+where
+    T: Send,
+    F: Fn(usize, &mut [T]) + Sync,
+{
+    let parallel = out.len() >= PARALLEL_THRESHOLD;
+    par_chunks_with(out, chunk_len, parallel, f);
+}
+
+/// [`par_chunks`] with an explicit choice of whether to use worker threads.
+///
+/// Workers pull chunks from a shared queue, so smaller chunks balance uneven
+/// work better at the cost of more synchronization.
+pub(crate) fn par_chunks_with<T, F>(out: &mut [T], chunk_len: usize, parallel: bool, f: F)
+where
+    T: Send,
+    F: Fn(usize, &mut [T]) + Sync,
+{
+    let chunk_len = chunk_len.max(1);
+    let workers = worker_count().min(out.len().div_ceil(chunk_len));
+    if !parallel || workers <= 1 {
+        out.chunks_mut(chunk_len)
+            .enumerate()
+            .for_each(|(i, chunk)| f(i, chunk));
+        return;
+    }
+
+    let queue = Mutex::new(out.chunks_mut(chunk_len).enumerate());
+    let (queue, f) = (&queue, &f);
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(move || {
+                loop {
+                    let next = queue.lock().unwrap().next();
+                    let Some((i, chunk)) = next else { break };
+                    f(i, chunk);
+                }
+            });
+        }
+    });
+}
+
+/// Folds `items` with the associative `f`, splitting large inputs across
+/// worker threads and combining the partial results in order. `identity`
+/// seeds every partial fold, so it must be neutral for `f`.
+pub(crate) fn par_reduce<T, F>(items: &[T], identity: T, f: F) -> T
+where
+    T: Copy + Send + Sync,
+    F: Fn(T, T) -> T + Sync,
+{
+    let workers = worker_count();
+    if items.len() < PARALLEL_THRESHOLD || workers <= 1 {
+        return items.iter().fold(identity, |acc, &x| f(acc, x));
+    }
+    let chunk_len = items.len().div_ceil(workers);
+    let f = &f;
+    thread::scope(|scope| {
+        let partials: Vec<_> = items
+            .chunks(chunk_len)
+            .map(|chunk| scope.spawn(move || chunk.iter().fold(identity, |acc, &x| f(acc, x))))
+            .collect();
+        partials
+            .into_iter()
+            .map(|p| p.join().unwrap())
+            .fold(identity, f)
+    })
+}
+
+/// Sets `out[i] = f(i)` for every index, in parallel for large outputs.
+pub(crate) fn par_fill<T, F>(out: &mut [T], f: F)
+where
+    T: Send,
+    F: Fn(usize) -> T + Sync,
+{
+    let chunk_len = out.len().div_ceil(worker_count()).max(1);
+    par_chunks(out, chunk_len, |c, chunk| {
+        let start = c * chunk_len;
+        for (i, slot) in chunk.iter_mut().enumerate() {
+            *slot = f(start + i);
+        }
+    });
+}
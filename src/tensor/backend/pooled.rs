@@ -0,0 +1,175 @@
+//! CPU backend that recycles buffers instead of allocating fresh ones.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use super::parallel::par_fill;
+use super::{Backend, CpuBackend};
+use crate::tensor::numeric::Numeric;
+
+/// A [`CpuBackend`] that draws output buffers from a pool of previously
+/// returned ones, cutting allocator churn in loops that keep producing
+/// same-shaped intermediates.
+///
+/// Buffers only come back through [`PooledCpuBackend::recycle`], typically
+/// with `backend.recycle(tensor.into_vec())` once an intermediate is no
+/// longer needed. Clones share the same pool. `matmul` is delegated to the
+/// plain CPU kernel and allocates as usual.
+#[derive(Debug, Clone)]
+pub struct PooledCpuBackend<T> {
+    pool: Arc<Mutex<HashMap<usize, Vec<Vec<T>>>>>,
+    allocations: Arc<AtomicUsize>,
+}
+
+impl<T> Default for PooledCpuBackend<T> {
+    fn default() -> Self {
+        Self {
+            pool: Arc::default(),
+            allocations: Arc::default(),
+        }
+    }
+}
+
+impl<T: Numeric> PooledCpuBackend<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hands `buffer` back to the pool for reuse by a later operation
+    /// producing the same number of elements.
+    pub fn recycle(&self, buffer: Vec<T>) {
+        self.pool
+            .lock()
+            .unwrap()
+            .entry(buffer.len())
+            .or_default()
+            .push(buffer);
+    }
+
+    /// How many buffers had to be freshly allocated because the pool had
+    /// none of the right size.
+    pub fn allocations(&self) -> usize {
+        self.allocations.load(Ordering::Relaxed)
+    }
+
+    /// A buffer of `len` elements with unspecified contents.
+    fn take(&self, len: usize) -> Vec<T> {
+        let reused = self.pool.lock().unwrap().get_mut(&len).and_then(Vec::pop);
+        reused.unwrap_or_else(|| {
+            self.allocations.fetch_add(1, Ordering::Relaxed);
+            vec![T::zero(); len]
+        })
+    }
+}
+
+impl<T: Numeric> Backend<T> for PooledCpuBackend<T> {
+    fn zeros(&self, shape: &[usize]) -> Vec<T> {
+        self.full(shape, T::zero())
+    }
+
+    fn ones(&self, shape: &[usize]) -> Vec<T> {
+        self.full(shape, T::one())
+    }
+
+    fn full(&self, shape: &[usize], value: T) -> Vec<T> {
+        let mut out = self.uninit(shape);
+        par_fill(&mut out, |_| value);
+        out
+    }
+
+    fn uninit(&self, shape: &[usize]) -> Vec<T> {
+        self.take(shape.iter().product())
+    }
+
+    fn add(&self, a: &[T], b: &[T]) -> Vec<T> {
+        self.zip_map(a, b, |x, y| x + y)
+    }
+
+    fn sub(&self, a: &[T], b: &[T]) -> Vec<T> {
+        self.zip_map(a, b, |x, y| x - y)
+    }
+
+    fn mul(&self, a: &[T], b: &[T]) -> Vec<T> {
+        self.zip_map(a, b, |x, y| x * y)
+    }
+
+    fn div(&self, a: &[T], b: &[T]) -> Vec<T> {
+        self.zip_map(a, b, |x, y| x / y)
+    }
+
+    fn fma(&self, a: &[T], b: &[T], c: &[T]) -> Vec<T> {
+        assert!(
+            a.len() == b.len() && a.len() == c.len(),
+            "buffer length mismatch"
+        );
+        let mut out = self.take(a.len());
+        par_fill(&mut out, |i| a[i].mul_add(b[i], c[i]));
+        out
+    }
+
+    fn matmul(&self, a: &[T], b: &[T], m: usize, k: usize, n: usize) -> Vec<T> {
+        CpuBackend::new().matmul(a, b, m, k, n)
+    }
+
+    fn bmm(&self, a: &[T], b: &[T], batch: usize, m: usize, k: usize, n: usize) -> Vec<T> {
+        CpuBackend::new().bmm(a, b, batch, m, k, n)
+    }
+
+    fn map<F>(&self, a: &[T], f: F) -> Vec<T>
+    where
+        F: Fn(T) -> T + Sync,
+    {
+        let mut out = self.take(a.len());
+        par_fill(&mut out, |i| f(a[i]));
+        out
+    }
+
+    fn zip_map<F>(&self, a: &[T], b: &[T], f: F) -> Vec<T>
+    where
+        F: Fn(T, T) -> T + Sync,
+    {
+        assert_eq!(a.len(), b.len(), "buffer length mismatch");
+        let mut out = self.take(a.len());
+        par_fill(&mut out, |i| f(a[i], b[i]));
+        out
+    }
+
+    fn fold<F>(&self, a: &[T], identity: T, f: F) -> T
+    where
+        F: Fn(T, T) -> T + Sync,
+    {
+        CpuBackend::new().fold(a, identity, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Tensor;
+
+    #[test]
+    fn recycled_buffers_are_reused() {
+        let backend = PooledCpuBackend::new();
+        let a = Tensor::full(&[64, 64], 1.5f32, backend.clone());
+        let b = Tensor::full(&[64, 64], 2.0f32, backend.clone());
+        assert_eq!(backend.allocations(), 2);
+
+        for _ in 0..10 {
+            let sum = &a + &b;
+            assert!(sum.data().iter().all(|&x| x == 3.5));
+            backend.recycle(sum.into_vec());
+        }
+        // Only the first sum needed a fresh buffer.
+        assert_eq!(backend.allocations(), 3);
+    }
+
+    #[test]
+    fn reused_buffers_are_fully_overwritten() {
+        let backend = PooledCpuBackend::new();
+        backend.recycle(vec![7i32; 4]);
+        let z: Tensor<i32, _> = Tensor::zeros(&[2, 2], backend.clone());
+        assert_eq!(z.data(), &[0; 4]);
+        assert_eq!(backend.allocations(), 0);
+    }
+}
@@ -0,0 +1,90 @@
+use std::mem::{size_of, size_of_val};
+
+use crate::tensor::Tensor;
+use crate::tensor::numeric::Pod;
+
+impl<T: Pod, B> Tensor<T, B> {
+    /// The elements as native-endian bytes, borrowed without copying, e.g.
+    /// to hand to C or upload to a GPU.
+    ///
+    /// Panics unless the tensor [is contiguous](Tensor::is_contiguous); call
+    /// [`Tensor::contiguous`] first for permuted or broadcast views.
+    pub fn as_bytes(&self) -> &[u8] {
+        assert!(
+            self.is_contiguous(),
+            "as_bytes needs a contiguous tensor, got strides {:?} for shape {:?}",
+            self.strides,
+            self.shape
+        );
+        let elements = &self.data[..self.numel()];
+        // SAFETY: `T: Pod` has no padding, so all `size_of_val(elements)`
+        // bytes are initialized, and `u8` has no alignment requirement.
+        unsafe { std::slice::from_raw_parts(elements.as_ptr().cast(), size_of_val(elements)) }
+    }
+
+    /// Row-major tensor of `shape` decoded from native-endian `bytes`, the
+    /// inverse of [`Tensor::as_bytes`].
+    ///
+    /// The bytes are copied, so they need no particular alignment. Panics
+    /// unless their length is exactly that of `shape`'s elements.
+    pub fn from_bytes(bytes: &[u8], shape: &[usize], backend: B) -> Self {
+        let len: usize = shape.iter().product();
+        assert_eq!(
+            bytes.len(),
+            len * size_of::<T>(),
+            "from_bytes needs {} bytes for shape {shape:?}, got {}",
+            len * size_of::<T>(),
+            bytes.len()
+        );
+        let data = bytes
+            .chunks_exact(size_of::<T>())
+            // SAFETY: each chunk holds `size_of::<T>()` bytes and any bit
+            // pattern is a valid `T: Pod`; `read_unaligned` allows any address.
+            .map(|chunk| unsafe { chunk.as_ptr().cast::<T>().read_unaligned() })
+            .collect();
+        Tensor::from_raw(data, shape, backend)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tensor::Tensor;
+    use crate::tensor::backend::CpuBackend;
+
+    #[test]
+    fn round_trip_through_bytes() {
+        let t = Tensor::from_data(
+            vec![1.5f32, -2.0, 0.0, 1e-3, 7.25, -0.5],
+            &[2, 3],
+            CpuBackend::new(),
+        );
+        let bytes = t.as_bytes();
+        assert_eq!(bytes.len(), 24);
+        assert_eq!(&bytes[..4], &1.5f32.to_ne_bytes());
+
+        let back = Tensor::<f32, _>::from_bytes(bytes, &[2, 3], CpuBackend::new());
+        assert_eq!(back, t);
+        assert_eq!(back.shape(), &[2, 3]);
+    }
+
+    #[test]
+    fn from_bytes_accepts_unaligned_input() {
+        let mut bytes = vec![0u8];
+        bytes.extend_from_slice(&3.0f64.to_ne_bytes());
+        let t = Tensor::<f64, _>::from_bytes(&bytes[1..], &[1], CpuBackend::new());
+        assert_eq!(t.data(), &[3.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "as_bytes needs a contiguous tensor")]
+    fn as_bytes_rejects_transposed_views() {
+        let t = Tensor::from_data(vec![1.0f64, 2.0, 3.0, 4.0], &[2, 2], CpuBackend::new());
+        t.transpose().as_bytes();
+    }
+
+    #[test]
+    #[should_panic(expected = "from_bytes needs 8 bytes")]
+    fn from_bytes_rejects_wrong_lengths() {
+        Tensor::<f32, _>::from_bytes(&[0; 6], &[2], CpuBackend::new());
+    }
+}
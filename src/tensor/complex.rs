@@ -0,0 +1,134 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::tensor::Tensor;
+use crate::tensor::backend::Backend;
+use crate::tensor::numeric::Numeric;
+
+/// A complex number with real part `re` and imaginary part `im`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[repr(C)]
+pub struct Complex<T> {
+    pub re: T,
+    pub im: T,
+}
+
+impl<T> Complex<T> {
+    pub fn new(re: T, im: T) -> Self {
+        Self { re, im }
+    }
+}
+
+impl<T: Numeric> Numeric for Complex<T> {}
+
+impl<T: Numeric> From<u8> for Complex<T> {
+    fn from(value: u8) -> Self {
+        Self::new(T::from(value), T::zero())
+    }
+}
+
+impl<T: Numeric> Add for Complex<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<T: Numeric> Sub for Complex<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<T: Numeric> Mul for Complex<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl<T: Numeric> Div for Complex<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        let denom = rhs.re * rhs.re + rhs.im * rhs.im;
+        Self::new(
+            (self.re * rhs.re + self.im * rhs.im) / denom,
+            (self.im * rhs.re - self.re * rhs.im) / denom,
+        )
+    }
+}
+
+impl<T: Numeric, B: Backend<T> + Backend<Complex<T>>> Tensor<T, B> {
+    /// Reads a trailing dimension of size 2 as `(re, im)` pairs, turning a
+    /// `[..., 2]` real tensor into a `[...]` complex one.
+    ///
+    /// The pairs are copied into a new buffer.
+    pub fn view_as_complex(&self) -> Tensor<Complex<T>, B> {
+        assert!(
+            self.shape.last() == Some(&2),
+            "view_as_complex needs a trailing dimension of size 2, got {:?}",
+            self.shape
+        );
+        let data = self
+            .values()
+            .chunks_exact(2)
+            .map(|pair| Complex::new(pair[0], pair[1]))
+            .collect();
+        Tensor::from_data(data, &self.shape[..self.ndim() - 1], self.backend.clone())
+    }
+}
+
+impl<T: Numeric, B: Backend<T> + Backend<Complex<T>>> Tensor<Complex<T>, B> {
+    /// Inverse of [`Tensor::view_as_complex`]: splits every element into a
+    /// trailing `(re, im)` dimension of size 2.
+    pub fn view_as_real(&self) -> Tensor<T, B> {
+        let data = self.values().iter().flat_map(|c| [c.re, c.im]).collect();
+        let mut shape = self.shape.clone();
+        shape.push(2);
+        Tensor::from_data(data, &shape, self.backend.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::backend::CpuBackend;
+
+    #[test]
+    fn complex_arithmetic() {
+        let a = Complex::new(1.0, 2.0);
+        let b = Complex::new(3.0, -1.0);
+        assert_eq!(a * b, Complex::new(5.0, 5.0));
+        assert_eq!((a * b) / b, a);
+        assert_eq!(a + b - b, a);
+    }
+
+    #[test]
+    fn real_complex_round_trip() {
+        let real = Tensor::from_data(
+            vec![1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0],
+            &[3, 2],
+            CpuBackend::new(),
+        );
+        let complex = real.view_as_complex();
+        assert_eq!(complex.shape(), &[3]);
+        assert_eq!(complex.get(&[1]), Complex::new(3.0, 4.0));
+
+        let back = complex.view_as_real();
+        assert_eq!(back, real);
+    }
+
+    #[test]
+    #[should_panic(expected = "trailing dimension of size 2")]
+    fn view_as_complex_requires_pairs() {
+        let t: Tensor<f32, _> = Tensor::zeros(&[2, 3], CpuBackend::new());
+        t.view_as_complex();
+    }
+}
@@ -0,0 +1,35 @@
+use std::error::Error;
+use std::fmt;
+
+/// Errors returned by the fallible tensor operations.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TensorError {
+    /// An operation name that isn't recognised by [`Tensor::apply_op`](crate::Tensor::apply_op).
+    UnknownOp(String),
+    /// A matrix that has no inverse, met while inverting or factorizing.
+    Singular,
+    /// A matrix given to [`Tensor::cholesky`](crate::Tensor::cholesky) that
+    /// isn't (numerically) positive definite.
+    NotPositiveDefinite,
+    /// An index with the wrong number of coordinates, or one past the end
+    /// of its dimension.
+    IndexOutOfBounds {
+        index: Vec<usize>,
+        shape: Vec<usize>,
+    },
+}
+
+impl fmt::Display for TensorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TensorError::UnknownOp(op) => write!(f, "unknown operation `{op}`"),
+            TensorError::Singular => write!(f, "matrix is singular"),
+            TensorError::NotPositiveDefinite => write!(f, "matrix is not positive definite"),
+            TensorError::IndexOutOfBounds { index, shape } => {
+                write!(f, "index {index:?} out of bounds for shape {shape:?}")
+            }
+        }
+    }
+}
+
+impl Error for TensorError {}
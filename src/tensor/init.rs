@@ -0,0 +1,110 @@
+use crate::tensor::Tensor;
+use crate::tensor::backend::Backend;
+use crate::tensor::numeric::{CastFrom, Float};
+use crate::tensor::random::Rng;
+
+/// Which fan [`Tensor::kaiming_uniform`] scales by: `FanIn` preserves the
+/// variance of activations in the forward pass, `FanOut` that of gradients
+/// in the backward pass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FanMode {
+    #[default]
+    FanIn,
+    FanOut,
+}
+
+/// `(fan_in, fan_out)` of a weight shaped `[out, in, kernel...]`, as for
+/// linear and convolution layers.
+fn fans(shape: &[usize]) -> (usize, usize) {
+    assert!(
+        shape.len() >= 2,
+        "fan-in and fan-out need at least 2 dimensions, got {shape:?}"
+    );
+    let receptive: usize = shape[2..].iter().product();
+    (shape[1] * receptive, shape[0] * receptive)
+}
+
+impl<T: Float + CastFrom<f64>, B: Backend<T>> Tensor<T, B> {
+    /// Xavier/Glorot uniform init: `U(-a, a)` with
+    /// `a = sqrt(6 / (fan_in + fan_out))`.
+    pub fn xavier_uniform(shape: &[usize], rng: &mut Rng, backend: B) -> Self {
+        let (fan_in, fan_out) = fans(shape);
+        let bound = (6.0 / (fan_in + fan_out) as f64).sqrt();
+        Self::sampled(shape, backend, || rng.uniform(-bound, bound))
+    }
+
+    /// Xavier/Glorot normal init: `N(0, 2 / (fan_in + fan_out))`.
+    pub fn xavier_normal(shape: &[usize], rng: &mut Rng, backend: B) -> Self {
+        let (fan_in, fan_out) = fans(shape);
+        let std = (2.0 / (fan_in + fan_out) as f64).sqrt();
+        Self::sampled(shape, backend, || rng.normal(0.0, std))
+    }
+
+    /// Kaiming/He uniform init for ReLU layers: `U(-a, a)` with
+    /// `a = sqrt(6 / fan)`, for the fan picked by `mode`.
+    pub fn kaiming_uniform(shape: &[usize], mode: FanMode, rng: &mut Rng, backend: B) -> Self {
+        let (fan_in, fan_out) = fans(shape);
+        let fan = match mode {
+            FanMode::FanIn => fan_in,
+            FanMode::FanOut => fan_out,
+        };
+        let bound = (6.0 / fan as f64).sqrt();
+        Self::sampled(shape, backend, || rng.uniform(-bound, bound))
+    }
+
+    fn sampled(shape: &[usize], backend: B, mut sample: impl FnMut() -> f64) -> Self {
+        let data = (0..shape.iter().product())
+            .map(|_| T::cast_from(sample()))
+            .collect();
+        Tensor::from_data(data, shape, backend)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::backend::CpuBackend;
+
+    fn std_of(t: &Tensor<f64, CpuBackend>) -> f64 {
+        t.std(None, false).item()
+    }
+
+    #[test]
+    fn xavier_std_matches_theory() {
+        let mut rng = Rng::seed_from_u64(0);
+        let expected = (2.0 / (256.0 + 512.0f64)).sqrt();
+        let uniform = Tensor::xavier_uniform(&[256, 512], &mut rng, CpuBackend::new());
+        let normal = Tensor::xavier_normal(&[256, 512], &mut rng, CpuBackend::new());
+        assert!((std_of(&uniform) / expected - 1.0).abs() < 0.01);
+        assert!((std_of(&normal) / expected - 1.0).abs() < 0.01);
+
+        let bound = (6.0 / 768.0f64).sqrt();
+        assert!(uniform.all(|x| x.abs() <= bound));
+    }
+
+    #[test]
+    fn kaiming_uses_the_chosen_fan() {
+        let mut rng = Rng::seed_from_u64(1);
+        // A conv weight [out, in, kh, kw]: fan_in = 16 * 9, fan_out = 64 * 9.
+        let shape = [64, 16, 3, 3];
+        let fan_in = Tensor::kaiming_uniform(&shape, FanMode::FanIn, &mut rng, CpuBackend::new());
+        let fan_out = Tensor::kaiming_uniform(&shape, FanMode::FanOut, &mut rng, CpuBackend::new());
+        assert!((std_of(&fan_in) / (2.0 / 144.0f64).sqrt() - 1.0).abs() < 0.02);
+        assert!((std_of(&fan_out) / (2.0 / 576.0f64).sqrt() - 1.0).abs() < 0.02);
+    }
+
+    #[test]
+    fn same_seed_same_weights() {
+        let a: Tensor<f32, _> =
+            Tensor::xavier_uniform(&[4, 3], &mut Rng::seed_from_u64(5), CpuBackend::new());
+        let b = Tensor::xavier_uniform(&[4, 3], &mut Rng::seed_from_u64(5), CpuBackend::new());
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least 2 dimensions")]
+    fn init_rejects_vectors() {
+        let _: Tensor<f32, _> =
+            Tensor::xavier_normal(&[10], &mut Rng::seed_from_u64(0), CpuBackend::new());
+    }
+}
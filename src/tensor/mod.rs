@@ -0,0 +1,834 @@
+mod accumulator;
+pub mod backend;
+mod bytes;
+mod complex;
+pub mod error;
+mod init;
+mod npy;
+pub mod numeric;
+mod ops;
+mod random;
+mod rolling;
+mod view;
+
+use std::borrow::Cow;
+use std::ops::{Index, IndexMut};
+
+pub use accumulator::Accumulator;
+pub use complex::Complex;
+pub use init::FanMode;
+pub use ops::{loss, where_};
+pub use random::Rng;
+pub use rolling::RollingPercentile;
+pub use view::TensorView;
+
+use backend::{Backend, CpuBackend};
+use error::TensorError;
+use numeric::Numeric;
+
+/// An n-dimensional array stored as a flat buffer plus shape and strides.
+///
+/// Strides are expressed in elements. A freshly built tensor is row-major and
+/// contiguous; view-like operations may rearrange strides without touching
+/// `data`, so element access always goes through the strides.
+#[derive(Debug, Clone)]
+pub struct Tensor<T, B> {
+    data: Vec<T>,
+    shape: Vec<usize>,
+    strides: Vec<usize>,
+    layout: Layout,
+    backend: B,
+}
+
+/// Order in which a tensor's buffer stores its elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Layout {
+    /// C order: the last axis varies fastest.
+    #[default]
+    RowMajor,
+    /// Fortran order: the first axis varies fastest, as BLAS and LAPACK
+    /// expect.
+    ColMajor,
+}
+
+/// Strides for a fresh buffer of `shape` stored in `layout` order.
+pub(crate) fn compute_strides(shape: &[usize], layout: Layout) -> Vec<usize> {
+    let mut strides = vec![1; shape.len()];
+    match layout {
+        Layout::RowMajor => {
+            for i in (0..shape.len().saturating_sub(1)).rev() {
+                strides[i] = strides[i + 1] * shape[i + 1];
+            }
+        }
+        Layout::ColMajor => {
+            for i in 1..shape.len() {
+                strides[i] = strides[i - 1] * shape[i - 1];
+            }
+        }
+    }
+    strides
+}
+
+/// Shape that `a` and `b` broadcast to under numpy rules, or `None` if they
+/// are incompatible.
+///
+/// Shapes are aligned from the trailing axis; each pair of dimensions must be
+/// equal or contain a 1.
+pub fn broadcast_shapes(a: &[usize], b: &[usize]) -> Option<Vec<usize>> {
+    let ndim = a.len().max(b.len());
+    let dim =
+        |shape: &[usize], i: usize| (i + shape.len()).checked_sub(ndim).map_or(1, |j| shape[j]);
+    (0..ndim)
+        .map(|i| match (dim(a, i), dim(b, i)) {
+            (x, y) if x == y => Some(x),
+            (1, y) => Some(y),
+            (x, 1) => Some(x),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Calls `f` with every multi-index of `shape`, in row-major order.
+pub(crate) fn for_each_index(shape: &[usize], mut f: impl FnMut(&[usize])) {
+    let count: usize = shape.iter().product();
+    let mut index = vec![0; shape.len()];
+    for _ in 0..count {
+        f(&index);
+        for axis in (0..index.len()).rev() {
+            index[axis] += 1;
+            if index[axis] < shape[axis] {
+                break;
+            }
+            index[axis] = 0;
+        }
+    }
+}
+
+impl<T, B> Tensor<T, B> {
+    /// Row-major tensor over `data` with no bounds on `T`, for element types
+    /// like `bool` that aren't [`Numeric`].
+    pub(crate) fn from_raw(data: Vec<T>, shape: &[usize], backend: B) -> Self {
+        let expected: usize = shape.iter().product();
+        assert_eq!(
+            data.len(),
+            expected,
+            "data length {} doesn't match shape {:?}",
+            data.len(),
+            shape
+        );
+        Self {
+            data,
+            shape: shape.to_vec(),
+            strides: compute_strides(shape, Layout::RowMajor),
+            layout: Layout::RowMajor,
+            backend,
+        }
+    }
+
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    pub fn strides(&self) -> &[usize] {
+        &self.strides
+    }
+
+    pub fn ndim(&self) -> usize {
+        self.shape.len()
+    }
+
+    /// Total number of elements.
+    pub fn numel(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    /// The underlying buffer, in storage order (see [`Tensor::strides`]).
+    pub fn data(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Storage order of the buffer; views keep the layout of the tensor they
+    /// were taken from.
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// Whether the buffer is laid out row-major, i.e. the strides are exactly
+    /// those of a freshly built tensor of this shape.
+    pub fn is_contiguous(&self) -> bool {
+        self.strides == compute_strides(&self.shape, Layout::RowMajor)
+    }
+
+    /// Buffer offset of the element at `indices`.
+    ///
+    /// Panics if the number of indices doesn't match the rank or any index is
+    /// out of bounds.
+    fn offset(&self, indices: &[usize]) -> usize {
+        assert_eq!(
+            indices.len(),
+            self.shape.len(),
+            "expected {} indices, got {}",
+            self.shape.len(),
+            indices.len()
+        );
+        for (&i, &dim) in indices.iter().zip(&self.shape) {
+            assert!(
+                i < dim,
+                "index {i} out of bounds for dimension of size {dim}"
+            );
+        }
+        self.unchecked_offset(indices)
+    }
+
+    /// Buffer offset of the element at `indices`, or `None` if they don't
+    /// address an element.
+    fn checked_offset(&self, indices: &[usize]) -> Option<usize> {
+        let valid = indices.len() == self.shape.len()
+            && indices.iter().zip(&self.shape).all(|(&i, &dim)| i < dim);
+        valid.then(|| self.unchecked_offset(indices))
+    }
+
+    fn unchecked_offset(&self, indices: &[usize]) -> usize {
+        indices.iter().zip(&self.strides).map(|(i, s)| i * s).sum()
+    }
+
+    /// Buffer offsets of every element, in logical row-major order.
+    pub(crate) fn offsets(&self) -> Vec<usize> {
+        let mut offsets = Vec::with_capacity(self.numel());
+        for_each_index(&self.shape, |index| {
+            offsets.push(index.iter().zip(&self.strides).map(|(i, s)| i * s).sum());
+        });
+        offsets
+    }
+}
+
+impl<T: Copy, B> Tensor<T, B> {
+    pub fn get(&self, indices: &[usize]) -> T {
+        self.data[self.offset(indices)]
+    }
+
+    pub fn set(&mut self, indices: &[usize], value: T) {
+        let offset = self.offset(indices);
+        self.data[offset] = value;
+    }
+
+    /// Like [`Tensor::get`], but `None` instead of a panic when `indices`
+    /// has the wrong length or is out of bounds.
+    pub fn try_get(&self, indices: &[usize]) -> Option<T> {
+        self.checked_offset(indices).map(|offset| self.data[offset])
+    }
+
+    /// Like [`Tensor::set`], but reports a bad index as
+    /// [`TensorError::IndexOutOfBounds`] instead of panicking.
+    pub fn try_set(&mut self, indices: &[usize], value: T) -> Result<(), TensorError> {
+        let offset = self
+            .checked_offset(indices)
+            .ok_or_else(|| TensorError::IndexOutOfBounds {
+                index: indices.to_vec(),
+                shape: self.shape.clone(),
+            })?;
+        self.data[offset] = value;
+        Ok(())
+    }
+
+    /// The only element of a one-element tensor, such as the `[1]` result of
+    /// a full reduction. Panics if there are more or fewer elements.
+    pub fn item(&self) -> T {
+        self.try_item().unwrap_or_else(|| {
+            panic!(
+                "item() needs exactly one element, got {} in shape {:?}",
+                self.numel(),
+                self.shape
+            )
+        })
+    }
+
+    /// Like [`Tensor::item`], but `None` unless there is exactly one element.
+    pub fn try_item(&self) -> Option<T> {
+        // Every index of a one-element tensor is zero, so is its offset.
+        (self.numel() == 1).then(|| self.data[0])
+    }
+
+    /// Copies the elements out in logical row-major order, so strided views
+    /// export the values they show rather than their buffer.
+    pub fn to_vec(&self) -> Vec<T> {
+        self.values().into_owned()
+    }
+
+    /// Like [`Tensor::to_vec`], but hands over the buffer without copying
+    /// when the tensor is contiguous.
+    pub fn into_vec(self) -> Vec<T> {
+        if self.is_contiguous() {
+            self.data
+        } else {
+            self.to_vec()
+        }
+    }
+
+    /// Elements in logical row-major order, regardless of strides.
+    ///
+    /// Borrows the buffer when it is already laid out row-major.
+    pub(crate) fn values(&self) -> Cow<'_, [T]> {
+        if self.is_contiguous() {
+            Cow::Borrowed(&self.data)
+        } else {
+            Cow::Owned(self.offsets().into_iter().map(|o| self.data[o]).collect())
+        }
+    }
+}
+
+/// `tensor[&[i, j]]` borrows the element at a multi-index, panicking like
+/// [`Tensor::get`] on a bad one.
+impl<T, B> Index<&[usize]> for Tensor<T, B> {
+    type Output = T;
+
+    fn index(&self, indices: &[usize]) -> &T {
+        &self.data[self.offset(indices)]
+    }
+}
+
+impl<T, B> IndexMut<&[usize]> for Tensor<T, B> {
+    fn index_mut(&mut self, indices: &[usize]) -> &mut T {
+        let offset = self.offset(indices);
+        &mut self.data[offset]
+    }
+}
+
+/// `tensor[[i, j]]`, without borrowing the index.
+impl<T, B, const N: usize> Index<[usize; N]> for Tensor<T, B> {
+    type Output = T;
+
+    fn index(&self, indices: [usize; N]) -> &T {
+        &self[&indices[..]]
+    }
+}
+
+impl<T, B, const N: usize> IndexMut<[usize; N]> for Tensor<T, B> {
+    fn index_mut(&mut self, indices: [usize; N]) -> &mut T {
+        &mut self[&indices[..]]
+    }
+}
+
+impl<T: Copy, B: Clone> Tensor<T, B> {
+    /// A view of `self` broadcast to `shape`, repeating size-1 (or missing
+    /// leading) dimensions through zero strides.
+    ///
+    /// Panics if `self` can't be broadcast to `shape`.
+    pub(crate) fn broadcast_to(&self, shape: &[usize]) -> Self {
+        assert!(
+            broadcast_shapes(&self.shape, shape).as_deref() == Some(shape),
+            "cannot broadcast {:?} to {:?}",
+            self.shape,
+            shape
+        );
+        let lead = shape.len() - self.ndim();
+        let strides = (0..shape.len())
+            .map(|i| match i.checked_sub(lead) {
+                Some(j) if self.shape[j] == shape[i] => self.strides[j],
+                _ => 0,
+            })
+            .collect();
+        Self {
+            data: self.data.clone(),
+            shape: shape.to_vec(),
+            strides,
+            layout: self.layout,
+            backend: self.backend.clone(),
+        }
+    }
+
+    /// Returns a tensor with the same logical elements laid out row-major.
+    ///
+    /// Already-contiguous tensors are simply cloned.
+    pub fn contiguous(&self) -> Self {
+        if self.is_contiguous() {
+            return self.clone();
+        }
+        Self {
+            data: self.values().into_owned(),
+            shape: self.shape.clone(),
+            strides: compute_strides(&self.shape, Layout::RowMajor),
+            layout: Layout::RowMajor,
+            backend: self.backend.clone(),
+        }
+    }
+
+    /// Returns a tensor with the same logical elements stored in `layout`
+    /// order, e.g. column-major for handing to Fortran code through
+    /// [`Tensor::data`].
+    pub fn with_layout(self, layout: Layout) -> Self {
+        match layout {
+            Layout::RowMajor => self.contiguous(),
+            Layout::ColMajor => {
+                // Column-major order is row-major order over the reversed
+                // axes.
+                let reversed: Vec<usize> = self.shape.iter().rev().copied().collect();
+                let mut data = Vec::with_capacity(self.numel());
+                let mut index = vec![0; self.ndim()];
+                for_each_index(&reversed, |r| {
+                    for (dst, &i) in index.iter_mut().zip(r.iter().rev()) {
+                        *dst = i;
+                    }
+                    data.push(self.get(&index));
+                });
+                Self {
+                    data,
+                    strides: compute_strides(&self.shape, Layout::ColMajor),
+                    shape: self.shape,
+                    layout,
+                    backend: self.backend,
+                }
+            }
+        }
+    }
+}
+
+impl<T: Numeric, B: Backend<T>> Tensor<T, B> {
+    /// Builds a row-major tensor from `data`.
+    ///
+    /// Panics if `data.len()` doesn't match the number of elements in `shape`.
+    pub fn from_data(data: Vec<T>, shape: &[usize], backend: B) -> Self {
+        Self::from_raw(data, shape, backend)
+    }
+
+    pub fn zeros(shape: &[usize], backend: B) -> Self {
+        let data = backend.zeros(shape);
+        Self::from_data(data, shape, backend)
+    }
+
+    pub fn ones(shape: &[usize], backend: B) -> Self {
+        let data = backend.ones(shape);
+        Self::from_data(data, shape, backend)
+    }
+
+    /// A tensor of `shape` with every element set to `value`.
+    pub fn full(shape: &[usize], value: T, backend: B) -> Self {
+        let data = backend.full(shape, value);
+        Self::from_data(data, shape, backend)
+    }
+
+    /// Builds a tensor by calling `f` with every multi-index of `shape`, in
+    /// row-major order.
+    pub fn from_fn<F: Fn(&[usize]) -> T>(shape: &[usize], f: F, backend: B) -> Self {
+        let mut data = Vec::with_capacity(shape.iter().product());
+        for_each_index(shape, |index| data.push(f(index)));
+        Self::from_data(data, shape, backend)
+    }
+
+    /// The `[n, n]` identity matrix.
+    pub fn eye(n: usize, backend: B) -> Self {
+        Self::eye_rect(n, n, backend)
+    }
+
+    /// A `[rows, cols]` matrix with ones on the main diagonal and zeros
+    /// elsewhere.
+    pub fn eye_rect(rows: usize, cols: usize, backend: B) -> Self {
+        let mut t = Self::zeros(&[rows, cols], backend);
+        for i in 0..rows.min(cols) {
+            t.set(&[i, i], T::one());
+        }
+        t
+    }
+
+    /// Allocates a tensor without promising anything about its contents.
+    ///
+    /// Every element must be written before it is read; reading first yields
+    /// whatever [`Backend::uninit`] left there, which may differ between
+    /// backends and between calls.
+    pub fn empty(shape: &[usize], backend: B) -> Self {
+        let data = backend.uninit(shape);
+        Self::from_data(data, shape, backend)
+    }
+
+    /// Zeros with the shape and backend of `self`.
+    pub fn zeros_like(&self) -> Self {
+        Self::zeros(&self.shape, self.backend.clone())
+    }
+
+    /// Ones with the shape and backend of `self`.
+    pub fn ones_like(&self) -> Self {
+        Self::ones(&self.shape, self.backend.clone())
+    }
+
+    /// A tensor filled with `value`, with the shape and backend of `self`.
+    pub fn full_like(&self, value: T) -> Self {
+        Self::full(&self.shape, value, self.backend.clone())
+    }
+
+    /// Copies each 1D lane along `axis` into a scratch buffer, lets `f`
+    /// rewrite it in place, and collects the results into a new tensor of the
+    /// same shape.
+    pub(crate) fn map_lanes<F>(&self, axis: usize, mut f: F) -> Self
+    where
+        F: FnMut(&mut [T]),
+    {
+        let (bases, step) = self.lane_bases(axis);
+        let mut data = self.values().into_owned();
+        let mut lane = vec![T::zero(); self.shape[axis]];
+        for base in bases {
+            for (i, x) in lane.iter_mut().enumerate() {
+                *x = data[base + i * step];
+            }
+            f(&mut lane);
+            for (i, &x) in lane.iter().enumerate() {
+                data[base + i * step] = x;
+            }
+        }
+        Tensor::from_data(data, &self.shape, self.backend.clone())
+    }
+
+    /// Replaces every 1D lane along `axis` with `f(lane)`, which must return
+    /// a lane of the same length.
+    pub fn apply_along_axis<F: Fn(&[T]) -> Vec<T>>(&self, axis: usize, f: F) -> Self {
+        assert!(
+            axis < self.ndim(),
+            "axis {axis} out of range for shape {:?}",
+            self.shape
+        );
+        self.map_lanes(axis, |lane| {
+            let out = f(lane);
+            assert_eq!(
+                out.len(),
+                lane.len(),
+                "apply_along_axis function returned {} elements for a lane of {}",
+                out.len(),
+                lane.len()
+            );
+            lane.copy_from_slice(&out);
+        })
+    }
+}
+
+/// Shortcuts for tensors on the default [`CpuBackend`].
+impl<T: Numeric> Tensor<T, CpuBackend> {
+    pub fn from_data_cpu(data: Vec<T>, shape: &[usize]) -> Self {
+        Self::from_data(data, shape, CpuBackend::new())
+    }
+
+    pub fn zeros_cpu(shape: &[usize]) -> Self {
+        Self::zeros(shape, CpuBackend::new())
+    }
+
+    pub fn ones_cpu(shape: &[usize]) -> Self {
+        Self::ones(shape, CpuBackend::new())
+    }
+}
+
+impl<T, B> Tensor<T, B> {
+    /// Start positions (into the row-major [`Tensor::values`] buffer) of every
+    /// lane along `axis`, plus the distance between consecutive lane elements.
+    ///
+    /// Lanes are listed in row-major order of the remaining axes, so the i-th
+    /// lane is element i of the tensor with `axis` removed.
+    pub(crate) fn lane_bases(&self, axis: usize) -> (Vec<usize>, usize) {
+        assert!(
+            axis < self.ndim(),
+            "axis {axis} out of range for shape {:?}",
+            self.shape
+        );
+        let n = self.shape[axis];
+        let inner: usize = self.shape[axis + 1..].iter().product();
+        let outer: usize = self.shape[..axis].iter().product();
+        let bases = (0..outer)
+            .flat_map(|o| (0..inner).map(move |r| o * n * inner + r))
+            .collect();
+        (bases, inner)
+    }
+
+    /// Shape with `axis` removed.
+    pub(crate) fn reduced_shape(&self, axis: usize) -> Vec<usize> {
+        let mut shape = self.shape.clone();
+        shape.remove(axis);
+        shape
+    }
+}
+
+impl<T: Copy, B> Tensor<T, B> {
+    /// Calls `f` on each lane along `axis` and collects one result per lane,
+    /// ordered like the elements of a tensor of shape `reduced_shape(axis)`.
+    pub(crate) fn reduce_lanes<R, F>(&self, axis: usize, mut f: F) -> Vec<R>
+    where
+        F: FnMut(&[T]) -> R,
+    {
+        let (bases, step) = self.lane_bases(axis);
+        let data = self.values();
+        let n = self.shape[axis];
+        let mut lane = Vec::with_capacity(n);
+        bases
+            .into_iter()
+            .map(|base| {
+                lane.clear();
+                lane.extend((0..n).map(|i| data[base + i * step]));
+                f(&lane)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strides_for_each_layout() {
+        assert_eq!(
+            compute_strides(&[2, 3, 4], Layout::RowMajor),
+            vec![12, 4, 1]
+        );
+        assert_eq!(compute_strides(&[2, 3, 4], Layout::ColMajor), vec![1, 2, 6]);
+        assert_eq!(compute_strides(&[5], Layout::RowMajor), vec![1]);
+        assert!(compute_strides(&[], Layout::ColMajor).is_empty());
+    }
+
+    #[test]
+    fn broadcast_shape_rules() {
+        assert_eq!(broadcast_shapes(&[2, 3], &[3]), Some(vec![2, 3]));
+        assert_eq!(broadcast_shapes(&[4, 1, 3], &[2, 1]), Some(vec![4, 2, 3]));
+        assert_eq!(broadcast_shapes(&[], &[5]), Some(vec![5]));
+        assert_eq!(broadcast_shapes(&[2, 3], &[2]), None);
+    }
+
+    #[test]
+    fn broadcast_to_repeats_through_zero_strides() {
+        let row = Tensor::from_data(vec![1, 2, 3], &[3], CpuBackend::new());
+        let b = row.broadcast_to(&[2, 3]);
+        assert_eq!(b.strides(), &[0, 1]);
+        assert_eq!(b.values().to_vec(), vec![1, 2, 3, 1, 2, 3]);
+    }
+
+    #[test]
+    fn get_and_set() {
+        let mut t = Tensor::from_data(
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+            &[2, 3],
+            CpuBackend::new(),
+        );
+        assert_eq!(t.get(&[1, 0]), 4.0);
+        t.set(&[0, 2], 9.0);
+        assert_eq!(t.get(&[0, 2]), 9.0);
+        assert_eq!(t.numel(), 6);
+    }
+
+    #[test]
+    fn zeros_and_ones() {
+        let z: Tensor<f32, _> = Tensor::zeros(&[2, 2], CpuBackend::new());
+        let o: Tensor<i32, _> = Tensor::ones(&[3], CpuBackend::new());
+        assert!(z.data().iter().all(|&x| x == 0.0));
+        assert_eq!(o.data(), &[1, 1, 1]);
+    }
+
+    #[test]
+    fn cpu_shortcut_constructors() {
+        let t = Tensor::from_data_cpu(vec![1, 2, 3, 4], &[2, 2]);
+        assert_eq!(
+            t,
+            Tensor::from_data(vec![1, 2, 3, 4], &[2, 2], CpuBackend::new())
+        );
+        let z: Tensor<f32, _> = Tensor::zeros_cpu(&[3]);
+        assert_eq!(z.data(), &[0.0; 3]);
+        let o: Tensor<f64, _> = Tensor::ones_cpu(&[2]);
+        assert_eq!(o.data(), &[1.0, 1.0]);
+    }
+
+    #[test]
+    fn from_fn_builds_a_multiplication_table() {
+        let t = Tensor::from_fn(&[3, 3], |ij| ij[0] * ij[1], CpuBackend::new());
+        assert_eq!(t.shape(), &[3, 3]);
+        assert_eq!(t.get(&[0, 2]), 0);
+        assert_eq!(t.get(&[1, 2]), 2);
+        assert_eq!(t.get(&[2, 2]), 4);
+        assert_eq!(t.data(), &[0, 0, 0, 0, 1, 2, 0, 2, 4]);
+    }
+
+    #[test]
+    fn apply_along_axis_normalizes_rows() {
+        let t = Tensor::from_data(
+            vec![1.0, 1.0, 2.0, 3.0, 0.0, 1.0],
+            &[2, 3],
+            CpuBackend::new(),
+        );
+        let normalized = t.apply_along_axis(1, |row| {
+            let sum: f64 = row.iter().sum();
+            row.iter().map(|x| x / sum).collect()
+        });
+        assert_eq!(normalized.shape(), &[2, 3]);
+        assert_eq!(normalized.data(), &[0.25, 0.25, 0.5, 0.75, 0.0, 0.25]);
+    }
+
+    #[test]
+    #[should_panic(expected = "returned 1 elements for a lane of 3")]
+    fn apply_along_axis_rejects_length_changes() {
+        let t: Tensor<f32, _> = Tensor::zeros(&[2, 3], CpuBackend::new());
+        t.apply_along_axis(1, |_| vec![0.0]);
+    }
+
+    #[test]
+    fn full_fills_every_element() {
+        let t: Tensor<f64, _> = Tensor::full(&[2, 2], 7.0, CpuBackend::new());
+        assert_eq!(t.shape(), &[2, 2]);
+        assert_eq!(t.data(), &[7.0; 4]);
+    }
+
+    #[test]
+    fn eye_has_ones_on_the_diagonal() {
+        let i: Tensor<i32, _> = Tensor::eye(3, CpuBackend::new());
+        assert_eq!(i.data(), &[1, 0, 0, 0, 1, 0, 0, 0, 1]);
+
+        let r: Tensor<i32, _> = Tensor::eye_rect(2, 3, CpuBackend::new());
+        assert_eq!(r.shape(), &[2, 3]);
+        assert_eq!(r.data(), &[1, 0, 0, 0, 1, 0]);
+    }
+
+    #[test]
+    fn eye_is_the_matmul_identity() {
+        let m = Tensor::from_data(
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+            &[2, 3],
+            CpuBackend::new(),
+        );
+        assert_eq!(Tensor::eye(2, CpuBackend::new()).matmul(&m), m);
+        assert_eq!(m.matmul(&Tensor::eye(3, CpuBackend::new())), m);
+    }
+
+    #[test]
+    fn empty_can_be_filled() {
+        let mut t: Tensor<f32, _> = Tensor::empty(&[2, 3], CpuBackend::new());
+        assert_eq!(t.shape(), &[2, 3]);
+        for i in 0..2 {
+            for j in 0..3 {
+                t.set(&[i, j], (i * 3 + j) as f32);
+            }
+        }
+        assert_eq!(t.data(), &[0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+    }
+
+    #[test]
+    fn like_constructors_reuse_shape() {
+        let t = Tensor::from_data(
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+            &[2, 3],
+            CpuBackend::new(),
+        );
+        let z = t.zeros_like();
+        assert_eq!(z.shape(), &[2, 3]);
+        assert!(z.data().iter().all(|&x| x == 0.0));
+        assert_eq!(t.ones_like().data(), &[1.0; 6]);
+        assert_eq!(t.transpose().full_like(7.0).shape(), &[3, 2]);
+        assert_eq!(t.full_like(7.0).data(), &[7.0; 6]);
+    }
+
+    #[test]
+    fn to_vec_exports_logical_order() {
+        let t = Tensor::from_data((0..6).collect::<Vec<i32>>(), &[2, 3], CpuBackend::new());
+        let p = t.permute(&[1, 0]);
+        assert_eq!(p.data(), &[0, 1, 2, 3, 4, 5]);
+        assert_eq!(p.to_vec(), vec![0, 3, 1, 4, 2, 5]);
+        assert_eq!(p.into_vec(), vec![0, 3, 1, 4, 2, 5]);
+        assert_eq!(t.into_vec(), vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn contiguity() {
+        let t = Tensor::from_data((0..6).collect::<Vec<i32>>(), &[2, 3], CpuBackend::new());
+        assert!(t.is_contiguous());
+
+        let p = t.permute(&[1, 0]);
+        assert!(!p.is_contiguous());
+
+        let c = p.contiguous();
+        assert!(c.is_contiguous());
+        assert_eq!(c.data(), &[0, 3, 1, 4, 2, 5]);
+        assert_eq!(c, p);
+    }
+
+    #[test]
+    fn with_layout_moves_elements_but_keeps_indexing() {
+        let t = Tensor::from_data((0..12).collect::<Vec<i32>>(), &[3, 4], CpuBackend::new());
+        assert_eq!(t.layout(), Layout::RowMajor);
+        assert_eq!(t.offset(&[1, 2]), 6);
+
+        let f = t.clone().with_layout(Layout::ColMajor);
+        assert_eq!(f.layout(), Layout::ColMajor);
+        assert_eq!(f.strides(), &[1, 3]);
+        assert_eq!(f.offset(&[1, 2]), 7);
+        assert_eq!(f.get(&[1, 2]), 6);
+        assert_eq!(&f.data()[..4], &[0, 4, 8, 1]);
+        assert_eq!(f, t);
+
+        let back = f.with_layout(Layout::RowMajor);
+        assert_eq!(back.layout(), Layout::RowMajor);
+        assert_eq!(back.data(), t.data());
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn get_out_of_bounds_panics() {
+        let t: Tensor<f32, _> = Tensor::zeros(&[2, 2], CpuBackend::new());
+        t.get(&[2, 0]);
+    }
+
+    #[test]
+    fn try_get_and_try_set_validate_indices() {
+        let mut t = Tensor::from_data(vec![1, 2, 3, 4, 5, 6], &[2, 3], CpuBackend::new());
+        assert_eq!(t.try_get(&[1, 2]), Some(6));
+        assert_eq!(t.transpose().try_get(&[2, 1]), Some(6));
+        assert_eq!(t.try_get(&[2, 0]), None);
+        assert_eq!(t.try_get(&[1]), None);
+        assert_eq!(t.try_get(&[0, 0, 0]), None);
+
+        assert_eq!(t.try_set(&[0, 1], 9), Ok(()));
+        assert_eq!(t.get(&[0, 1]), 9);
+        assert_eq!(
+            t.try_set(&[0, 3], 9),
+            Err(TensorError::IndexOutOfBounds {
+                index: vec![0, 3],
+                shape: vec![2, 3],
+            })
+        );
+        assert!(t.try_set(&[0], 9).is_err());
+        assert_eq!(t.data(), &[1, 9, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn index_operators_match_get_and_set() {
+        let mut t = Tensor::from_data(vec![1, 2, 3, 4], &[2, 2], CpuBackend::new());
+        assert_eq!(t[&[1, 1][..]], t.get(&[1, 1]));
+        assert_eq!(t[[1, 0]], 3);
+        assert_eq!(t.transpose()[[1, 0]], 2);
+
+        t[&[1, 1][..]] = 9;
+        assert_eq!(t.get(&[1, 1]), 9);
+        t[[0, 1]] += 10;
+        assert_eq!(t.data(), &[1, 12, 3, 9]);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn index_out_of_bounds_panics() {
+        let t: Tensor<f32, _> = Tensor::zeros(&[2, 2], CpuBackend::new());
+        let _ = t[[0, 2]];
+    }
+
+    #[test]
+    fn item_of_a_full_reduction() {
+        let t = Tensor::from_data(vec![1.5, 2.5, 3.0], &[3], CpuBackend::new());
+        assert_eq!(t.sum(None).item(), 7.0);
+        assert_eq!(t.sum(None).try_item(), Some(7.0));
+        assert_eq!(t.try_item(), None);
+        assert_eq!(
+            Tensor::from_data(vec![4], &[1, 1], CpuBackend::new()).item(),
+            4
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "item() needs exactly one element, got 3")]
+    fn item_rejects_multi_element_tensors() {
+        let t: Tensor<f32, _> = Tensor::zeros(&[3], CpuBackend::new());
+        t.item();
+    }
+}
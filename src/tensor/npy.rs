@@ -0,0 +1,273 @@
+//! Reading and writing NumPy's `.npy` format (version 1.0, with 2.0 headers
+//! also accepted on load), for exchanging `f32`/`f64` arrays with Python.
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+use crate::tensor::{Layout, Tensor, compute_strides};
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+macro_rules! impl_npy {
+    ($t:ty, $kind:literal) => {
+        impl<B: Clone> Tensor<$t, B> {
+            #[doc = concat!("Writes the tensor to `path` as a row-major `.npy` file of dtype `<", $kind, "`.")]
+            pub fn save_npy(&self, path: impl AsRef<Path>) -> io::Result<()> {
+                let mut data = Vec::with_capacity(self.numel() * size_of::<$t>());
+                for x in self.values().iter() {
+                    data.extend_from_slice(&x.to_le_bytes());
+                }
+                write_npy(path.as_ref(), concat!("<", $kind), &self.shape, &data)
+            }
+
+            #[doc = concat!("Reads a `.npy` file of dtype `", $kind, "` (either byte order).")]
+            ///
+            /// Fortran-ordered arrays are converted to row-major. Anything
+            /// else that isn't a well-formed file of this dtype is an
+            /// [`io::ErrorKind::InvalidData`] error.
+            pub fn load_npy(path: impl AsRef<Path>, backend: B) -> io::Result<Self> {
+                let npy = read_npy(path.as_ref(), $kind, size_of::<$t>())?;
+                let data = npy
+                    .data
+                    .chunks_exact(size_of::<$t>())
+                    .map(|chunk| {
+                        let bytes = chunk.try_into().unwrap();
+                        if npy.big_endian {
+                            <$t>::from_be_bytes(bytes)
+                        } else {
+                            <$t>::from_le_bytes(bytes)
+                        }
+                    })
+                    .collect();
+                Ok(from_npy_order(data, &npy.shape, npy.fortran_order, backend))
+            }
+        }
+    };
+}
+
+impl_npy!(f32, "f4");
+impl_npy!(f64, "f8");
+
+/// The parts of a `.npy` file needed to rebuild a tensor.
+struct Npy {
+    big_endian: bool,
+    fortran_order: bool,
+    shape: Vec<usize>,
+    data: Vec<u8>,
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn write_npy(path: &Path, descr: &str, shape: &[usize], data: &[u8]) -> io::Result<()> {
+    let shape = match shape {
+        [n] => format!("({n},)"),
+        _ => format!(
+            "({})",
+            shape
+                .iter()
+                .map(usize::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+    let mut header = format!("{{'descr': '{descr}', 'fortran_order': False, 'shape': {shape}, }}");
+    // Like numpy, pad with spaces so the data starts on a 64-byte boundary,
+    // counting the magic, version, length field and trailing newline.
+    let unpadded = MAGIC.len() + 4 + header.len() + 1;
+    header.extend(std::iter::repeat_n(
+        ' ',
+        unpadded.next_multiple_of(64) - unpadded,
+    ));
+    header.push('\n');
+    let header_len = u16::try_from(header.len())
+        .map_err(|_| invalid(format!("npy header too long for shape {shape}")))?;
+
+    let mut out = io::BufWriter::new(fs::File::create(path)?);
+    out.write_all(MAGIC)?;
+    out.write_all(&[1, 0])?;
+    out.write_all(&header_len.to_le_bytes())?;
+    out.write_all(header.as_bytes())?;
+    out.write_all(data)?;
+    out.flush()
+}
+
+/// Reads the file and checks its dtype is `kind` (e.g. `"f4"`) with
+/// `elem_size`-byte elements, one per element of the shape.
+fn read_npy(path: &Path, kind: &str, elem_size: usize) -> io::Result<Npy> {
+    let bytes = fs::read(path)?;
+    let rest = bytes
+        .strip_prefix(MAGIC)
+        .ok_or_else(|| invalid("not an npy file: bad magic string".to_string()))?;
+    let (header_len, rest) = match rest {
+        [1, _, a, b, rest @ ..] => (usize::from(u16::from_le_bytes([*a, *b])), rest),
+        [2 | 3, _, a, b, c, d, rest @ ..] => (u32::from_le_bytes([*a, *b, *c, *d]) as usize, rest),
+        [major, ..] => return Err(invalid(format!("unsupported npy version {major}"))),
+        [] => return Err(invalid("truncated npy file".to_string())),
+    };
+    if rest.len() < header_len {
+        return Err(invalid("truncated npy header".to_string()));
+    }
+    let (header, data) = rest.split_at(header_len);
+    let header = std::str::from_utf8(header)
+        .map_err(|_| invalid("npy header is not valid text".to_string()))?;
+
+    let descr = header_value(header, "descr")?;
+    let descr = descr
+        .strip_prefix('\'')
+        .and_then(|d| d.split('\'').next())
+        .ok_or_else(|| invalid(format!("malformed npy descr in {header:?}")))?;
+    let big_endian = match descr.split_at_checked(1) {
+        Some(("<", k)) if k == kind => false,
+        Some((">", k)) if k == kind => true,
+        _ => return Err(invalid(format!("expected npy dtype {kind}, got '{descr}'"))),
+    };
+
+    let fortran = header_value(header, "fortran_order")?;
+    let fortran_order = if fortran.starts_with("True") {
+        true
+    } else if fortran.starts_with("False") {
+        false
+    } else {
+        return Err(invalid(format!(
+            "malformed npy fortran_order in {header:?}"
+        )));
+    };
+
+    let shape = header_value(header, "shape")?;
+    let shape = shape
+        .strip_prefix('(')
+        .and_then(|s| s.split(')').next())
+        .ok_or_else(|| invalid(format!("malformed npy shape in {header:?}")))?;
+    let shape = shape
+        .split(',')
+        .map(str::trim)
+        .filter(|dim| !dim.is_empty())
+        .map(|dim| {
+            dim.parse()
+                .map_err(|_| invalid(format!("bad npy dimension {dim:?}")))
+        })
+        .collect::<io::Result<Vec<usize>>>()?;
+
+    let expected = shape.iter().product::<usize>() * elem_size;
+    if data.len() != expected {
+        return Err(invalid(format!(
+            "npy data for shape {shape:?} should be {expected} bytes, got {}",
+            data.len()
+        )));
+    }
+    Ok(Npy {
+        big_endian,
+        fortran_order,
+        shape,
+        data: data.to_vec(),
+    })
+}
+
+/// The text following `'key':` in a header dict, with leading spaces
+/// trimmed.
+fn header_value<'a>(header: &'a str, key: &str) -> io::Result<&'a str> {
+    let quoted = format!("'{key}'");
+    header
+        .split_once(&quoted)
+        .and_then(|(_, rest)| rest.trim_start().strip_prefix(':'))
+        .map(str::trim_start)
+        .ok_or_else(|| invalid(format!("npy header has no '{key}' entry")))
+}
+
+/// Row-major tensor over `data`, which is in column-major order when
+/// `fortran_order` is set.
+fn from_npy_order<T: Copy, B: Clone>(
+    data: Vec<T>,
+    shape: &[usize],
+    fortran_order: bool,
+    backend: B,
+) -> Tensor<T, B> {
+    if !fortran_order {
+        return Tensor::from_raw(data, shape, backend);
+    }
+    Tensor {
+        data,
+        shape: shape.to_vec(),
+        strides: compute_strides(shape, Layout::ColMajor),
+        layout: Layout::ColMajor,
+        backend,
+    }
+    .contiguous()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::tensor::backend::CpuBackend;
+
+    fn scratch_file(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("kranium-{name}-{}.npy", std::process::id()))
+    }
+
+    fn fixture(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures")
+            .join(name)
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let path = scratch_file("round-trip");
+        let t = Tensor::from_data(
+            vec![1.5f32, -2.0, 0.25, 1e-3, 7.0, -0.5],
+            &[3, 2],
+            CpuBackend::new(),
+        );
+        t.save_npy(&path).unwrap();
+
+        let bytes = fs::read(&path).unwrap();
+        assert_eq!(&bytes[..8], b"\x93NUMPY\x01\x00");
+        assert_eq!((bytes.len() - 6 * 4) % 64, 0);
+
+        let back = Tensor::<f32, _>::load_npy(&path, CpuBackend::new()).unwrap();
+        assert_eq!(back, t);
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn saves_strided_tensors_in_logical_order() {
+        let path = scratch_file("strided");
+        let t =
+            Tensor::from_data(vec![1.0f64, 2.0, 3.0, 4.0], &[2, 2], CpuBackend::new()).transpose();
+        t.save_npy(&path).unwrap();
+        let back = Tensor::<f64, _>::load_npy(&path, CpuBackend::new()).unwrap();
+        assert_eq!(back.data(), &[1.0, 3.0, 2.0, 4.0]);
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn loads_a_numpy_fixture() {
+        // np.save(path, np.arange(6, dtype='<f8').reshape(2, 3))
+        let t =
+            Tensor::<f64, _>::load_npy(fixture("arange_2x3_f8.npy"), CpuBackend::new()).unwrap();
+        assert_eq!(t.shape(), &[2, 3]);
+        assert_eq!(t.data(), &[0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+    }
+
+    #[test]
+    fn loads_fortran_order_as_row_major() {
+        // np.save(path, np.asfortranarray(np.arange(6, dtype='<f4').reshape(2, 3)))
+        let path = fixture("arange_2x3_f4_fortran.npy");
+        let t = Tensor::<f32, _>::load_npy(path, CpuBackend::new()).unwrap();
+        assert!(t.is_contiguous());
+        assert_eq!(t.data(), &[0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+    }
+
+    #[test]
+    fn load_rejects_the_wrong_dtype() {
+        let err = Tensor::<f32, _>::load_npy(fixture("arange_2x3_f8.npy"), CpuBackend::new())
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("expected npy dtype f4"));
+    }
+}
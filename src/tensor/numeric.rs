@@ -0,0 +1,247 @@
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Element types a [`Tensor`](crate::Tensor) can do arithmetic on.
+///
+/// Implemented for the primitive integers and floats that can be built from a
+/// `u8` (which gives us `0` and `1`), and for [`Complex`](crate::Complex).
+pub trait Numeric:
+    Copy
+    + Debug
+    + PartialEq
+    + Send
+    + Sync
+    + 'static
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + From<u8>
+{
+    fn zero() -> Self {
+        Self::from(0)
+    }
+
+    fn one() -> Self {
+        Self::from(1)
+    }
+
+    /// `self * a + b`, fused into a single rounding where the type supports
+    /// it.
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+}
+
+macro_rules! impl_numeric {
+    ($($t:ty),*) => {
+        $(impl Numeric for $t {})*
+    };
+}
+
+impl_numeric!(u8, u16, u32, u64, u128, usize, i16, i32, i64, i128);
+
+impl Numeric for f32 {
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        f32::mul_add(self, a, b)
+    }
+}
+
+impl Numeric for f64 {
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        f64::mul_add(self, a, b)
+    }
+}
+
+/// Element types with a sign: the signed integers and the floats.
+pub trait Signed: Numeric + PartialOrd + Neg<Output = Self> {
+    fn abs(self) -> Self;
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),*) => {
+        $(
+            impl Signed for $t {
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_signed!(i16, i32, i64, i128, f32, f64);
+
+/// Floating-point element types, exposing the math functions that only make
+/// sense for them.
+pub trait Float: Signed {
+    fn exp(self) -> Self;
+
+    fn ln(self) -> Self;
+
+    fn sqrt(self) -> Self;
+
+    fn powf(self, exponent: Self) -> Self;
+
+    fn floor(self) -> Self;
+
+    /// Difference between `1.0` and the next larger representable value.
+    fn epsilon() -> Self;
+
+    fn is_nan(self) -> bool;
+
+    fn is_finite(self) -> bool;
+
+    fn is_infinite(self) -> bool;
+
+    /// Number of representable values between `self` and `other`; `0` for
+    /// equal values (including `0.0` vs `-0.0`) and `u64::MAX` if either is
+    /// NaN.
+    fn ulps_between(self, other: Self) -> u64;
+}
+
+macro_rules! impl_float {
+    ($($t:ty => $bits:ty),*) => {
+        $(
+            impl Float for $t {
+                fn exp(self) -> Self {
+                    <$t>::exp(self)
+                }
+
+                fn ln(self) -> Self {
+                    <$t>::ln(self)
+                }
+
+                fn sqrt(self) -> Self {
+                    <$t>::sqrt(self)
+                }
+
+                fn powf(self, exponent: Self) -> Self {
+                    <$t>::powf(self, exponent)
+                }
+
+                fn floor(self) -> Self {
+                    <$t>::floor(self)
+                }
+
+                fn epsilon() -> Self {
+                    <$t>::EPSILON
+                }
+
+                fn is_nan(self) -> bool {
+                    <$t>::is_nan(self)
+                }
+
+                fn is_finite(self) -> bool {
+                    <$t>::is_finite(self)
+                }
+
+                fn is_infinite(self) -> bool {
+                    <$t>::is_infinite(self)
+                }
+
+                fn ulps_between(self, other: Self) -> u64 {
+                    if self.is_nan() || other.is_nan() {
+                        return u64::MAX;
+                    }
+                    // Map the sign-magnitude bit patterns onto a monotonic
+                    // integer line where -0.0 and 0.0 coincide.
+                    let ordered = |x: $t| {
+                        let bits = x.to_bits() as $bits;
+                        if bits < 0 {
+                            i128::from(<$bits>::MIN) - i128::from(bits)
+                        } else {
+                            i128::from(bits)
+                        }
+                    };
+                    (ordered(self) - ordered(other)).unsigned_abs() as u64
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32 => i32, f64 => i64);
+
+/// Element-wise conversion used by [`Tensor::cast`](crate::Tensor::cast).
+///
+/// Conversions follow Rust's `as` semantics: float-to-integer truncates
+/// towards zero and saturates, integer-to-integer wraps.
+pub trait CastFrom<T> {
+    fn cast_from(value: T) -> Self;
+}
+
+macro_rules! impl_cast_from {
+    ($($src:ty),*) => {
+        $(impl_cast_from!(@to $src => u8, i32, i64, usize, f32, f64);)*
+    };
+    (@to $src:ty => $($dst:ty),*) => {
+        $(
+            impl CastFrom<$src> for $dst {
+                fn cast_from(value: $src) -> Self {
+                    value as $dst
+                }
+            }
+        )*
+    };
+}
+
+impl_cast_from!(u8, i32, i64, usize, f32, f64);
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Element types whose buffers can be viewed as raw bytes, see
+/// [`Tensor::as_bytes`](crate::Tensor::as_bytes).
+///
+/// Sealed: only types with no padding bytes and no invalid bit patterns
+/// implement it.
+///
+/// # Safety
+///
+/// Implementors must be plain old data: every byte initialized, and every
+/// bit pattern of the right size a valid value.
+pub unsafe trait Pod: Copy + sealed::Sealed {}
+
+macro_rules! impl_pod {
+    ($($t:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+            // SAFETY: primitive numbers have no padding and accept any bits.
+            unsafe impl Pod for $t {}
+        )*
+    };
+}
+
+impl_pod!(u8, i32, i64, f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::Numeric;
+    use crate::tensor::Tensor;
+    use crate::tensor::backend::CpuBackend;
+
+    /// `[0, 1, 2, ...]` built only from `Numeric` constants.
+    fn ramp<T: Numeric>(len: usize) -> Tensor<T, CpuBackend> {
+        let mut next = T::zero();
+        let data = (0..len)
+            .map(|_| {
+                let x = next;
+                next = next + T::one();
+                x
+            })
+            .collect();
+        Tensor::from_data(data, &[len], CpuBackend::new())
+    }
+
+    #[test]
+    fn integer_and_float_tensors_share_generic_code() {
+        let ints = ramp::<i32>(4);
+        let floats = ramp::<f32>(4);
+        assert_eq!(ints.data(), &[0, 1, 2, 3]);
+        assert_eq!(floats.data(), &[0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(ints.sum(None).data(), &[6]);
+        assert_eq!(floats.sum(None).data(), &[6.0]);
+    }
+}
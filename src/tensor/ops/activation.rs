@@ -0,0 +1,95 @@
+use crate::tensor::Tensor;
+use crate::tensor::backend::Backend;
+use crate::tensor::numeric::Float;
+
+/// Numerically stable softmax of `lane` in place (the max is subtracted
+/// before exponentiating). An empty lane is left empty.
+fn softmax_in_place<T: Float>(lane: &mut [T]) {
+    let Some(&first) = lane.first() else {
+        return;
+    };
+    let max = lane
+        .iter()
+        .copied()
+        .fold(first, |m, x| if x > m { x } else { m });
+    let mut sum = T::zero();
+    for x in lane.iter_mut() {
+        *x = (*x - max).exp();
+        sum = sum + *x;
+    }
+    for x in lane.iter_mut() {
+        *x = *x / sum;
+    }
+}
+
+impl<T: Float, B: Backend<T>> Tensor<T, B> {
+    /// Softmax along `axis`: each lane is exponentiated and normalized to sum
+    /// to one.
+    pub fn softmax(&self, axis: usize) -> Self {
+        self.map_lanes(axis, softmax_in_place)
+    }
+
+    /// Softmax along the last axis, e.g. over the classes of a
+    /// `[batch, classes]` tensor.
+    ///
+    /// Same result as `softmax(ndim - 1)`, but works on whole rows at once
+    /// instead of gathering strided lanes.
+    pub fn softmax_last(&self) -> Self {
+        assert!(self.ndim() > 0, "softmax needs at least one dimension");
+        let n = self.shape[self.ndim() - 1];
+        let mut data = self.values().into_owned();
+        if n > 0 {
+            data.chunks_mut(n).for_each(softmax_in_place);
+        }
+        Tensor::from_data(data, &self.shape, self.backend.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tensor::Tensor;
+    use crate::tensor::backend::CpuBackend;
+
+    #[test]
+    fn softmax_rows_sum_to_one() {
+        let t = Tensor::from_data(
+            vec![1.0f64, 2.0, 3.0, 1.0, 1.0, 1.0],
+            &[2, 3],
+            CpuBackend::new(),
+        );
+        let s = t.softmax(1);
+        assert!((s.get(&[1, 0]) - 1.0 / 3.0).abs() < 1e-12);
+        let row: f64 = (0..3).map(|j| s.get(&[0, j])).sum();
+        assert!((row - 1.0).abs() < 1e-12);
+        assert!(s.get(&[0, 2]) > s.get(&[0, 1]));
+    }
+
+    #[test]
+    fn softmax_along_first_axis() {
+        let t = Tensor::from_data(vec![0.0f64, 5.0, 0.0, 5.0], &[2, 2], CpuBackend::new());
+        let s = t.softmax(0);
+        assert!((s.get(&[0, 0]) - 0.5).abs() < 1e-12);
+        assert!((s.get(&[0, 1]) + s.get(&[1, 1]) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn softmax_last_matches_generic_softmax() {
+        let data: Vec<f32> = (0..40).map(|i| ((i * 7) % 11) as f32 * 0.3 - 1.5).collect();
+        let t = Tensor::from_data(data, &[4, 10], CpuBackend::new());
+        assert_eq!(t.softmax_last(), t.softmax(1));
+    }
+
+    #[test]
+    fn softmax_of_empty_lanes_is_empty() {
+        let t = Tensor::<f32, _>::zeros(&[2, 0], CpuBackend::new());
+        assert_eq!(t.softmax(1).shape(), &[2, 0]);
+        assert_eq!(t.softmax_last().shape(), &[2, 0]);
+        assert_eq!(t.softmax(0).shape(), &[2, 0]);
+    }
+
+    #[test]
+    fn softmax_is_stable_for_large_inputs() {
+        let t = Tensor::from_data(vec![1000.0f32, 1000.0], &[2], CpuBackend::new());
+        assert_eq!(t.softmax_last().data(), &[0.5, 0.5]);
+    }
+}
@@ -0,0 +1,42 @@
+use crate::tensor::Tensor;
+use crate::tensor::backend::Backend;
+use crate::tensor::numeric::{CastFrom, Numeric};
+
+impl<T: Numeric, B: Backend<T>> Tensor<T, B> {
+    /// Converts every element to `U`, keeping the shape and a clone of the
+    /// backend.
+    pub fn cast<U>(&self) -> Tensor<U, B>
+    where
+        U: Numeric + CastFrom<T>,
+        B: Backend<U>,
+    {
+        let data = self.values().iter().map(|&x| U::cast_from(x)).collect();
+        Tensor::from_data(data, &self.shape, self.backend.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tensor::Tensor;
+    use crate::tensor::backend::CpuBackend;
+
+    #[test]
+    fn cast_u8_to_f32() {
+        let t = Tensor::from_data(vec![0u8, 127, 255], &[3], CpuBackend::new());
+        let f = t.cast::<f32>();
+        assert_eq!(f.shape(), &[3]);
+        assert_eq!(f.data(), &[0.0, 127.0, 255.0]);
+    }
+
+    #[test]
+    fn cast_float_to_int_truncates() {
+        let t = Tensor::from_data(vec![1.9f64, -2.5, 3.0], &[3], CpuBackend::new());
+        assert_eq!(t.cast::<i32>().data(), &[1, -2, 3]);
+    }
+
+    #[test]
+    fn cast_respects_strides() {
+        let t = Tensor::from_data(vec![1i32, 2, 3, 4], &[2, 2], CpuBackend::new()).transpose();
+        assert_eq!(t.cast::<f64>().data(), &[1.0, 3.0, 2.0, 4.0]);
+    }
+}
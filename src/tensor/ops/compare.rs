@@ -0,0 +1,339 @@
+use crate::tensor::numeric::Float;
+use crate::tensor::{Tensor, broadcast_shapes};
+
+/// Tensors are equal when they have the same shape and the same elements in
+/// logical order; strides and backends are not compared.
+impl<T: Copy + PartialEq, B> PartialEq for Tensor<T, B> {
+    fn eq(&self, other: &Self) -> bool {
+        self.shape == other.shape && self.values() == other.values()
+    }
+}
+
+impl<T: Copy, B> Tensor<T, B> {
+    /// Approximate equality, element by element: `|a - b| <= atol + rtol * |b|`.
+    ///
+    /// Tensors of different shapes are never close.
+    pub fn allclose(&self, other: &Self, atol: f64, rtol: f64) -> bool
+    where
+        T: Into<f64>,
+    {
+        self.shape == other.shape
+            && self
+                .values()
+                .iter()
+                .zip(other.values().iter())
+                .all(|(&a, &b)| {
+                    let (a, b): (f64, f64) = (a.into(), b.into());
+                    (a - b).abs() <= atol + rtol * b.abs()
+                })
+    }
+}
+
+impl<T: Float, B> Tensor<T, B> {
+    /// Whether every pair of elements is at most `max_ulps` representable
+    /// floats apart. NaNs never compare equal; shapes must match.
+    pub fn eq_ulps(&self, other: &Self, max_ulps: u32) -> bool {
+        self.shape == other.shape
+            && self
+                .values()
+                .iter()
+                .zip(other.values().iter())
+                .all(|(&a, &b)| a.ulps_between(b) <= u64::from(max_ulps))
+    }
+}
+
+macro_rules! comparison {
+    ($(#[$doc:meta])* $name:ident, $scalar:ident, $op:tt) => {
+        $(#[$doc])*
+        pub fn $name(&self, other: &Self) -> Tensor<bool, B> {
+            let shape = broadcast_shapes(&self.shape, &other.shape).unwrap_or_else(|| {
+                panic!(
+                    "shapes {:?} and {:?} can't be broadcast together",
+                    self.shape, other.shape
+                )
+            });
+            let (a, b) = (self.broadcast_to(&shape), other.broadcast_to(&shape));
+            let data = a
+                .values()
+                .iter()
+                .zip(b.values().iter())
+                .map(|(a, b)| a $op b)
+                .collect();
+            Tensor::from_raw(data, &shape, self.backend.clone())
+        }
+
+        $(#[$doc])*
+        pub fn $scalar(&self, value: T) -> Tensor<bool, B> {
+            let data = self.values().iter().map(|a| *a $op value).collect();
+            Tensor::from_raw(data, &self.shape, self.backend.clone())
+        }
+    };
+}
+
+/// Element-wise comparisons producing boolean masks. Tensor operands are
+/// broadcast against each other.
+impl<T: Copy + PartialOrd, B: Clone> Tensor<T, B> {
+    comparison!(
+        /// Element-wise `>`.
+        gt, gt_scalar, >
+    );
+    comparison!(
+        /// Element-wise `<`.
+        lt, lt_scalar, <
+    );
+    comparison!(
+        /// Element-wise `>=`.
+        ge, ge_scalar, >=
+    );
+    comparison!(
+        /// Element-wise `<=`.
+        le, le_scalar, <=
+    );
+    comparison!(
+        /// Element-wise `==`. Unlike `PartialEq`, this yields a mask rather
+        /// than a single `bool`.
+        eq, eq_scalar, ==
+    );
+}
+
+impl<T: Copy, B: Clone> Tensor<T, B> {
+    /// Takes elements from `self` where `mask` is true and from `other`
+    /// elsewhere. All three tensors must share a shape.
+    pub fn where_mask(&self, mask: &Tensor<bool, B>, other: &Self) -> Self {
+        assert!(
+            self.shape == mask.shape && self.shape == other.shape,
+            "shape mismatch: {:?}, mask {:?}, other {:?}",
+            self.shape,
+            mask.shape,
+            other.shape
+        );
+        let data = self
+            .values()
+            .iter()
+            .zip(mask.values().iter())
+            .zip(other.values().iter())
+            .map(|((&a, &m), &b)| if m { a } else { b })
+            .collect();
+        Tensor::from_raw(data, &self.shape, self.backend.clone())
+    }
+
+    /// Copy of `self` with `value` written wherever `mask` is true, e.g. to
+    /// hide future positions from attention scores.
+    ///
+    /// `mask` must broadcast to the shape of `self`, which the result keeps.
+    pub fn masked_fill(&self, mask: &Tensor<bool, B>, value: T) -> Self {
+        let mask = mask.broadcast_to(&self.shape);
+        let data = self
+            .values()
+            .iter()
+            .zip(mask.values().iter())
+            .map(|(&x, &m)| if m { value } else { x })
+            .collect();
+        Tensor::from_raw(data, &self.shape, self.backend.clone())
+    }
+}
+
+/// Element-wise select: `a` where `mask` is true, `b` elsewhere.
+///
+/// The three shapes broadcast together under numpy rules, so a scalar-like
+/// `[1]` tensor can stand in for either branch.
+pub fn where_<T: Copy, B: Clone>(
+    mask: &Tensor<bool, B>,
+    a: &Tensor<T, B>,
+    b: &Tensor<T, B>,
+) -> Tensor<T, B> {
+    let shape = broadcast_shapes(&mask.shape, &a.shape)
+        .and_then(|s| broadcast_shapes(&s, &b.shape))
+        .unwrap_or_else(|| {
+            panic!(
+                "cannot broadcast mask {:?} with {:?} and {:?}",
+                mask.shape, a.shape, b.shape
+            )
+        });
+    let (mask, a, b) = (
+        mask.broadcast_to(&shape),
+        a.broadcast_to(&shape),
+        b.broadcast_to(&shape),
+    );
+    let data = mask
+        .values()
+        .iter()
+        .zip(a.values().iter())
+        .zip(b.values().iter())
+        .map(|((&m, &x), &y)| if m { x } else { y })
+        .collect();
+    Tensor::from_raw(data, &shape, a.backend.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::where_;
+    use crate::tensor::Tensor;
+    use crate::tensor::backend::CpuBackend;
+
+    #[test]
+    fn equal_tensors_compare_equal() {
+        let a = Tensor::from_data(vec![1.0f32, 2.0, 3.0, 4.0], &[2, 2], CpuBackend::new());
+        let b = Tensor::from_data(vec![1.0f32, 2.0, 3.0, 4.0], &[2, 2], CpuBackend::new());
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn different_shapes_are_unequal() {
+        let a = Tensor::from_data(vec![1, 2, 3, 4], &[2, 2], CpuBackend::new());
+        let b = Tensor::from_data(vec![1, 2, 3, 4], &[4], CpuBackend::new());
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn equality_uses_logical_order() {
+        let a = Tensor::from_data(vec![1, 2, 3, 4], &[2, 2], CpuBackend::new());
+        let at = Tensor::from_data(vec![1, 3, 2, 4], &[2, 2], CpuBackend::new());
+        assert_eq!(a.transpose(), at);
+        assert_ne!(a.transpose().data(), at.data());
+    }
+
+    #[test]
+    fn eq_ulps_counts_representable_steps() {
+        let one = 1.0f32;
+        let next = f32::from_bits(one.to_bits() + 1);
+        let a = Tensor::from_data(vec![one, -2.0], &[2], CpuBackend::new());
+        let b = Tensor::from_data(vec![next, -2.0], &[2], CpuBackend::new());
+        assert!(a.eq_ulps(&b, 1));
+        assert!(!a.eq_ulps(&b, 0));
+        assert!(a.eq_ulps(&a, 0));
+    }
+
+    #[test]
+    fn eq_ulps_across_zero_and_nan() {
+        let tiny = f64::from_bits(1);
+        let a = Tensor::from_data(vec![-tiny, 0.0], &[2], CpuBackend::new());
+        let b = Tensor::from_data(vec![tiny, -0.0], &[2], CpuBackend::new());
+        assert!(a.eq_ulps(&b, 2));
+        assert!(!a.eq_ulps(&b, 1));
+
+        let nan = Tensor::from_data(vec![f64::NAN], &[1], CpuBackend::new());
+        assert!(!nan.eq_ulps(&nan, u32::MAX));
+    }
+
+    #[test]
+    fn gt_scalar_builds_a_mask() {
+        let t = Tensor::from_data(
+            vec![-1.0, 0.0, 2.5, -0.5, 3.0, 0.1],
+            &[2, 3],
+            CpuBackend::new(),
+        );
+        let mask = t.gt_scalar(0.0);
+        assert_eq!(mask.shape(), &[2, 3]);
+        assert_eq!(mask.data(), &[false, false, true, false, true, true]);
+    }
+
+    #[test]
+    fn tensor_comparisons() {
+        let a = Tensor::from_data(vec![1, 2, 3], &[3], CpuBackend::new());
+        let b = Tensor::from_data(vec![3, 2, 1], &[3], CpuBackend::new());
+        assert_eq!(a.lt(&b).data(), &[true, false, false]);
+        assert_eq!(a.ge(&b).data(), &[false, true, true]);
+        assert_eq!(a.le(&b).data(), &[true, true, false]);
+        assert_eq!(a.eq(&b).data(), &[false, true, false]);
+    }
+
+    #[test]
+    fn comparisons_broadcast_a_threshold_row() {
+        let m = Tensor::from_data(
+            vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0],
+            &[2, 3],
+            CpuBackend::new(),
+        );
+        let thresholds = Tensor::from_data(vec![2.0, 3.0, 4.0], &[3], CpuBackend::new());
+        let mask = m.gt(&thresholds);
+        assert_eq!(mask.shape(), &[2, 3]);
+        assert_eq!(mask.data(), &[false, true, false, true, false, true]);
+        assert_eq!(thresholds.lt(&m), mask);
+    }
+
+    #[test]
+    #[should_panic(expected = "can't be broadcast")]
+    fn comparisons_reject_incompatible_shapes() {
+        let a = Tensor::from_data(vec![1, 2, 3], &[3], CpuBackend::new());
+        let b = Tensor::from_data(vec![1, 2], &[2], CpuBackend::new());
+        a.gt(&b);
+    }
+
+    #[test]
+    fn where_mask_selects_per_element() {
+        let a = Tensor::from_data(vec![1.0, -2.0, 3.0, -4.0], &[4], CpuBackend::new());
+        let zeros = Tensor::zeros(&[4], CpuBackend::new());
+        let relu = a.where_mask(&a.gt_scalar(0.0), &zeros);
+        assert_eq!(relu.data(), &[1.0, 0.0, 3.0, 0.0]);
+    }
+
+    #[test]
+    fn masked_fill_hides_the_upper_triangle() {
+        let scores = Tensor::from_data(vec![1.0f32; 9], &[3, 3], CpuBackend::new());
+        #[rustfmt::skip]
+        let future = Tensor::from_raw(vec![
+            false, true, true,
+            false, false, true,
+            false, false, false,
+        ], &[3, 3], CpuBackend::new());
+        let masked = scores.masked_fill(&future, -1e9);
+        #[rustfmt::skip]
+        assert_eq!(masked.data(), &[
+            1.0, -1e9, -1e9,
+            1.0, 1.0, -1e9,
+            1.0, 1.0, 1.0,
+        ]);
+    }
+
+    #[test]
+    fn masked_fill_broadcasts_the_mask() {
+        let t = Tensor::from_data(vec![1, 2, 3, 4], &[2, 2], CpuBackend::new());
+        let column = Tensor::from_raw(vec![true, false], &[2], CpuBackend::new());
+        assert_eq!(t.masked_fill(&column, 0).data(), &[0, 2, 0, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot broadcast")]
+    fn masked_fill_rejects_incompatible_masks() {
+        let t = Tensor::from_data(vec![1, 2, 3, 4], &[2, 2], CpuBackend::new());
+        let mask = Tensor::from_raw(vec![true; 3], &[3], CpuBackend::new());
+        t.masked_fill(&mask, 0);
+    }
+
+    #[test]
+    fn where_selects_with_alternating_mask() {
+        let mask = Tensor::from_raw(vec![true, false, true, false], &[4], CpuBackend::new());
+        let a = Tensor::from_data(vec![1, 2, 3, 4], &[4], CpuBackend::new());
+        let b = Tensor::from_data(vec![10, 20, 30, 40], &[4], CpuBackend::new());
+        assert_eq!(where_(&mask, &a, &b).data(), &[1, 20, 3, 40]);
+    }
+
+    #[test]
+    fn where_broadcasts_its_operands() {
+        let a = Tensor::from_data(vec![1, 2, 3, 4], &[2, 2], CpuBackend::new());
+        let zero = Tensor::from_data(vec![0], &[1], CpuBackend::new());
+        let row_mask = Tensor::from_raw(vec![false, true], &[2, 1], CpuBackend::new());
+        let out = where_(&row_mask, &a, &zero);
+        assert_eq!(out.shape(), &[2, 2]);
+        assert_eq!(out.data(), &[0, 0, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot broadcast")]
+    fn where_rejects_incompatible_shapes() {
+        let mask = Tensor::from_raw(vec![true; 3], &[3], CpuBackend::new());
+        let a = Tensor::from_data(vec![1, 2], &[2], CpuBackend::new());
+        where_(&mask, &a, &a);
+    }
+
+    #[test]
+    fn allclose_tolerates_small_differences() {
+        let a = Tensor::from_data(vec![1.0f32, 2.0], &[2], CpuBackend::new());
+        let b = Tensor::from_data(vec![1.0f32 + 1e-7, 2.0], &[2], CpuBackend::new());
+        assert_ne!(a, b);
+        assert!(a.allclose(&b, 1e-8, 1e-5));
+        let c = Tensor::from_data(vec![1.1f32, 2.0], &[2], CpuBackend::new());
+        assert!(!a.allclose(&c, 1e-8, 1e-5));
+    }
+}
@@ -0,0 +1,137 @@
+use crate::tensor::Tensor;
+use crate::tensor::backend::Backend;
+use crate::tensor::numeric::Numeric;
+
+impl<T: Numeric, B: Backend<T>> Tensor<T, B> {
+    /// 2D cross-correlation (the deep-learning "convolution").
+    ///
+    /// `self` is `[batch, in_channels, h, w]` and `kernel` is
+    /// `[out_channels, in_channels, kh, kw]`; the output is
+    /// `[batch, out_channels, out_h, out_w]` with
+    /// `out_h = (h + 2 * padding - kh) / stride + 1` (likewise for `out_w`).
+    /// Each image is unrolled with im2col and multiplied by the flattened
+    /// kernel using the backend matmul.
+    pub fn conv2d(&self, kernel: &Self, stride: usize, padding: usize) -> Self {
+        assert_eq!(
+            self.ndim(),
+            4,
+            "conv2d expects a [batch, channels, h, w] input, got {:?}",
+            self.shape
+        );
+        assert_eq!(
+            kernel.ndim(),
+            4,
+            "conv2d expects an [out, in, kh, kw] kernel, got {:?}",
+            kernel.shape
+        );
+        assert!(stride > 0, "conv2d stride must be positive");
+        let (batch, channels, h, w) = (self.shape[0], self.shape[1], self.shape[2], self.shape[3]);
+        let (out_channels, kc, kh, kw) = (
+            kernel.shape[0],
+            kernel.shape[1],
+            kernel.shape[2],
+            kernel.shape[3],
+        );
+        assert_eq!(
+            channels, kc,
+            "conv2d input has {channels} channels but the kernel expects {kc}"
+        );
+        let (ph, pw) = (h + 2 * padding, w + 2 * padding);
+        assert!(
+            kh <= ph && kw <= pw,
+            "conv2d kernel {kh}x{kw} is larger than the padded input {ph}x{pw}"
+        );
+        let out_h = (ph - kh) / stride + 1;
+        let out_w = (pw - kw) / stride + 1;
+
+        let input = self.pad(
+            &[(0, 0), (0, 0), (padding, padding), (padding, padding)],
+            T::zero(),
+        );
+        let input = input.data();
+        let weights = kernel.values();
+        let patch = channels * kh * kw;
+        let positions = out_h * out_w;
+
+        let mut out = Vec::with_capacity(batch * out_channels * positions);
+        let mut cols = vec![T::zero(); patch * positions];
+        for n in 0..batch {
+            let image = &input[n * channels * ph * pw..(n + 1) * channels * ph * pw];
+            // im2col: row (c, ki, kj) holds that kernel tap for every output position.
+            for c in 0..channels {
+                for ki in 0..kh {
+                    for kj in 0..kw {
+                        let row = (c * kh + ki) * kw + kj;
+                        for oi in 0..out_h {
+                            for oj in 0..out_w {
+                                let (y, x) = (oi * stride + ki, oj * stride + kj);
+                                cols[row * positions + oi * out_w + oj] =
+                                    image[(c * ph + y) * pw + x];
+                            }
+                        }
+                    }
+                }
+            }
+            out.extend(
+                self.backend
+                    .matmul(&weights, &cols, out_channels, patch, positions),
+            );
+        }
+        Tensor::from_data(
+            out,
+            &[batch, out_channels, out_h, out_w],
+            self.backend.clone(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tensor::Tensor;
+    use crate::tensor::backend::CpuBackend;
+
+    fn image() -> Tensor<f32, CpuBackend> {
+        Tensor::from_data(
+            (1..=9).map(|x| x as f32).collect(),
+            &[1, 1, 3, 3],
+            CpuBackend::new(),
+        )
+    }
+
+    #[test]
+    fn conv2d_single_channel() {
+        let kernel = Tensor::from_data(vec![1.0, 0.0, 0.0, 1.0], &[1, 1, 2, 2], CpuBackend::new());
+        let out = image().conv2d(&kernel, 1, 0);
+        assert_eq!(out.shape(), &[1, 1, 2, 2]);
+        // out[i][j] = x[i][j] + x[i + 1][j + 1]
+        assert_eq!(out.data(), &[6.0, 8.0, 12.0, 14.0]);
+    }
+
+    #[test]
+    fn conv2d_with_stride_and_padding() {
+        let kernel = Tensor::ones(&[1, 1, 2, 2], CpuBackend::new());
+        let out = image().conv2d(&kernel, 2, 1);
+        assert_eq!(out.shape(), &[1, 1, 2, 2]);
+        assert_eq!(out.data(), &[1.0, 5.0, 11.0, 28.0]);
+    }
+
+    #[test]
+    fn conv2d_multiple_channels() {
+        let input = Tensor::from_data(
+            vec![1.0, 2.0, 3.0, 4.0, 10.0, 20.0, 30.0, 40.0],
+            &[1, 2, 2, 2],
+            CpuBackend::new(),
+        );
+        // Output channel 0 sums both inputs, channel 1 takes their difference.
+        let kernel = Tensor::from_data(
+            vec![
+                1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, -1.0, -1.0, -1.0, -1.0, 1.0, 1.0, 1.0, 1.0,
+            ],
+            &[2, 2, 2, 2],
+            CpuBackend::new(),
+        );
+        let out = input.conv2d(&kernel, 1, 0);
+        assert_eq!(out.shape(), &[1, 2, 1, 1]);
+        assert_eq!(out.data(), &[110.0, 90.0]);
+    }
+}
@@ -0,0 +1,392 @@
+use crate::tensor::backend::Backend;
+use crate::tensor::numeric::Numeric;
+use crate::tensor::{Layout, Tensor, compute_strides, for_each_index};
+
+impl<T: Numeric, B: Backend<T>> Tensor<T, B> {
+    /// Einstein summation over `operands`, numpy style.
+    ///
+    /// `spec` lists one group of single-letter labels per operand, separated
+    /// by commas, then optionally `->` and the output labels, e.g.
+    /// `"ij,jk->ik"` for a matmul, `"bij,bjk->bik"` for a batched one,
+    /// `"ij->ji"` for a transpose or `"ii->"` for a trace. Labels repeated
+    /// within an operand take its diagonal; labels missing from the output
+    /// are summed over. Without `->`, the output holds every label that
+    /// appears exactly once, alphabetically.
+    ///
+    /// A result with no labels comes back as a `[1]` tensor, like
+    /// [`Tensor::sum`] over everything. Panics on a malformed spec or on
+    /// operands whose shapes disagree with it.
+    ///
+    /// One or two operands without repeated labels are lowered to sums,
+    /// permutes and a [`Tensor::bmm_strided`], so contractions run through
+    /// the backend's matmul. Diagonals, and more than two operands, fall back
+    /// to a direct loop over every label.
+    pub fn einsum(spec: &str, operands: &[&Self]) -> Self {
+        let (inputs, output) = parse_spec(spec);
+        assert_eq!(
+            inputs.len(),
+            operands.len(),
+            "einsum spec {spec:?} names {} operands, got {}",
+            inputs.len(),
+            operands.len()
+        );
+
+        // Every label with its size: output labels first, then the summed
+        // ones, so the leading indices of the loop below address the output.
+        let mut labels: Vec<char> = Vec::new();
+        let mut sizes: Vec<usize> = Vec::new();
+        for (subs, t) in inputs.iter().zip(operands) {
+            assert_eq!(
+                subs.len(),
+                t.ndim(),
+                "einsum subscripts {:?} don't match shape {:?}",
+                subs.iter().collect::<String>(),
+                t.shape
+            );
+            for (&label, &dim) in subs.iter().zip(&t.shape) {
+                match labels.iter().position(|&l| l == label) {
+                    Some(p) => assert_eq!(
+                        sizes[p], dim,
+                        "einsum label '{label}' has size {} and {dim}",
+                        sizes[p]
+                    ),
+                    None => {
+                        labels.push(label);
+                        sizes.push(dim);
+                    }
+                }
+            }
+        }
+        for &label in &output {
+            assert!(
+                labels.contains(&label),
+                "einsum output label '{label}' doesn't appear in any operand"
+            );
+        }
+
+        let diagonal = inputs
+            .iter()
+            .any(|subs| (0..subs.len()).any(|i| subs[..i].contains(&subs[i])));
+        let out = match (operands, diagonal) {
+            (&[a], false) => contract_one(a, &inputs[0], &output),
+            (&[a, b], false) => contract_two(a, &inputs[0], b, &inputs[1], &output),
+            _ => einsum_loop(&inputs, &output, operands, &labels, &sizes),
+        };
+        if output.is_empty() {
+            out.reshape(&[1])
+        } else {
+            out
+        }
+    }
+}
+
+/// Sums `t` over every axis whose label fails `keep`, returning the result
+/// and the labels of the axes left.
+fn sum_out<T: Numeric, B: Backend<T>>(
+    t: &Tensor<T, B>,
+    subs: &[char],
+    keep: impl Fn(char) -> bool,
+) -> (Tensor<T, B>, Vec<char>) {
+    let mut t = t.clone();
+    let mut subs = subs.to_vec();
+    for axis in (0..subs.len()).rev() {
+        if !keep(subs[axis]) {
+            t = t.sum(Some(axis));
+            subs.remove(axis);
+        }
+    }
+    (t, subs)
+}
+
+/// Axes of `subs` in the order their labels appear in `order`.
+fn axes_of(subs: &[char], order: &[char]) -> Vec<usize> {
+    order
+        .iter()
+        .map(|l| subs.iter().position(|s| s == l).unwrap())
+        .collect()
+}
+
+/// Single operand without diagonals: a sum over the dropped labels, then a
+/// permute into output order.
+fn contract_one<T: Numeric, B: Backend<T>>(
+    t: &Tensor<T, B>,
+    subs: &[char],
+    output: &[char],
+) -> Tensor<T, B> {
+    if output.is_empty() {
+        return t.sum(None);
+    }
+    let (t, subs) = sum_out(t, subs, |l| output.contains(&l));
+    t.permute(&axes_of(&subs, output)).contiguous()
+}
+
+/// Two operands without diagonals, lowered to one batched matmul: labels in
+/// both operands and the output are the batch, those in both but not the
+/// output are contracted, and the rest are the rows of `a` or the columns of
+/// `b`.
+fn contract_two<T: Numeric, B: Backend<T>>(
+    a: &Tensor<T, B>,
+    a_subs: &[char],
+    b: &Tensor<T, B>,
+    b_subs: &[char],
+    output: &[char],
+) -> Tensor<T, B> {
+    // Labels only one operand has and the output drops are summed up front.
+    let (a, a_subs) = sum_out(a, a_subs, |l| output.contains(&l) || b_subs.contains(&l));
+    let (b, b_subs) = sum_out(b, b_subs, |l| output.contains(&l) || a_subs.contains(&l));
+
+    let batch: Vec<char> = output
+        .iter()
+        .copied()
+        .filter(|l| a_subs.contains(l) && b_subs.contains(l))
+        .collect();
+    let rows: Vec<char> = output
+        .iter()
+        .copied()
+        .filter(|l| a_subs.contains(l) && !b_subs.contains(l))
+        .collect();
+    let cols: Vec<char> = output
+        .iter()
+        .copied()
+        .filter(|l| b_subs.contains(l) && !a_subs.contains(l))
+        .collect();
+    let inner: Vec<char> = a_subs
+        .iter()
+        .copied()
+        .filter(|l| b_subs.contains(l) && !output.contains(l))
+        .collect();
+
+    let size = |t: &Tensor<T, B>, subs: &[char], group: &[char]| -> Vec<usize> {
+        axes_of(subs, group)
+            .iter()
+            .map(|&axis| t.shape[axis])
+            .collect()
+    };
+    let (batch_dims, row_dims) = (size(&a, &a_subs, &batch), size(&a, &a_subs, &rows));
+    let col_dims = size(&b, &b_subs, &cols);
+    let product = |dims: &[usize]| dims.iter().product::<usize>();
+    let (nb, m, n) = (product(&batch_dims), product(&row_dims), product(&col_dims));
+    let k = product(&size(&a, &a_subs, &inner));
+
+    let lhs_order = [&batch[..], &rows, &inner].concat();
+    let rhs_order = [&batch[..], &inner, &cols].concat();
+    let lhs = a
+        .permute(&axes_of(&a_subs, &lhs_order))
+        .reshape(&[nb, m, k]);
+    let rhs = b
+        .permute(&axes_of(&b_subs, &rhs_order))
+        .reshape(&[nb, k, n]);
+
+    let labels = [&batch[..], &rows, &cols].concat();
+    let dims = [&batch_dims[..], &row_dims, &col_dims].concat();
+    lhs.bmm_strided(&rhs)
+        .reshape(&dims)
+        .permute(&axes_of(&labels, output))
+        .contiguous()
+}
+
+/// The general case: every combination of label values, multiplying the
+/// operands' elements and adding into the output.
+fn einsum_loop<T: Numeric, B: Backend<T>>(
+    inputs: &[Vec<char>],
+    output: &[char],
+    operands: &[&Tensor<T, B>],
+    labels: &[char],
+    sizes: &[usize],
+) -> Tensor<T, B> {
+    let mut order = output.to_vec();
+    order.extend(labels.iter().filter(|l| !output.contains(l)));
+    let size_of = |label: char| sizes[labels.iter().position(|&l| l == label).unwrap()];
+    let loop_shape: Vec<usize> = order.iter().map(|&l| size_of(l)).collect();
+    let out_shape = &loop_shape[..output.len()];
+
+    // Per operand, the buffer stride of each loop index. A label repeated
+    // within one operand adds its strides, which walks the diagonal.
+    let strides: Vec<Vec<usize>> = inputs
+        .iter()
+        .zip(operands)
+        .map(|(subs, t)| {
+            let mut strides = vec![0; order.len()];
+            for (&label, &stride) in subs.iter().zip(&t.strides) {
+                strides[order.iter().position(|&l| l == label).unwrap()] += stride;
+            }
+            strides
+        })
+        .collect();
+    let out_strides = compute_strides(out_shape, Layout::RowMajor);
+
+    let mut out = vec![T::zero(); out_shape.iter().product()];
+    for_each_index(&loop_shape, |index| {
+        let product = operands.iter().zip(&strides).fold(T::one(), |acc, (t, s)| {
+            let offset: usize = index.iter().zip(s).map(|(i, s)| i * s).sum();
+            acc * t.data[offset]
+        });
+        let at: usize = index.iter().zip(&out_strides).map(|(i, s)| i * s).sum();
+        out[at] = out[at] + product;
+    });
+
+    let backend = operands
+        .first()
+        .expect("einsum needs an operand")
+        .backend
+        .clone();
+    Tensor::from_data(out, out_shape, backend)
+}
+
+/// Splits `"ij,jk->ik"` into per-operand labels and output labels, deriving
+/// the implicit output when there is no `->`.
+fn parse_spec(spec: &str) -> (Vec<Vec<char>>, Vec<char>) {
+    let spec: String = spec.chars().filter(|c| !c.is_whitespace()).collect();
+    let (lhs, rhs) = match spec.split_once("->") {
+        Some((lhs, rhs)) => (lhs, Some(rhs)),
+        None => (spec.as_str(), None),
+    };
+    let labels = |group: &str| -> Vec<char> {
+        group
+            .chars()
+            .inspect(|c| {
+                assert!(
+                    c.is_ascii_alphabetic(),
+                    "einsum labels must be letters, got '{c}' in {spec:?}"
+                )
+            })
+            .collect()
+    };
+    let inputs: Vec<Vec<char>> = lhs.split(',').map(labels).collect();
+
+    let output = match rhs {
+        Some(rhs) => {
+            let output = labels(rhs);
+            for (i, label) in output.iter().enumerate() {
+                assert!(
+                    !output[..i].contains(label),
+                    "einsum output label '{label}' repeated in {spec:?}"
+                );
+            }
+            output
+        }
+        None => {
+            let all: Vec<char> = inputs.iter().flatten().copied().collect();
+            let mut once: Vec<char> = all
+                .iter()
+                .copied()
+                .filter(|&l| all.iter().filter(|&&m| m == l).count() == 1)
+                .collect();
+            once.sort_unstable();
+            once
+        }
+    };
+    (inputs, output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{einsum_loop, parse_spec};
+    use crate::tensor::Tensor;
+    use crate::tensor::backend::CpuBackend;
+
+    #[test]
+    fn einsum_reproduces_matmul() {
+        let a = Tensor::from_data((1..=6).collect(), &[2, 3], CpuBackend::new());
+        let b = Tensor::from_data((1..=12).collect(), &[3, 4], CpuBackend::new());
+        assert_eq!(Tensor::einsum("ij,jk->ik", &[&a, &b]), a.matmul(&b));
+        assert_eq!(Tensor::einsum("ij,jk", &[&a, &b]), a.matmul(&b));
+    }
+
+    #[test]
+    fn einsum_trace_and_transpose() {
+        let m = Tensor::from_data((1..=9).collect(), &[3, 3], CpuBackend::new());
+        assert_eq!(Tensor::einsum("ii->", &[&m]).data(), &[m.trace()]);
+        assert_eq!(Tensor::einsum("ij->ji", &[&m]), m.transpose());
+        assert_eq!(Tensor::einsum("ii->i", &[&m]), m.diag());
+    }
+
+    #[test]
+    fn einsum_batch_matmul() {
+        let a = Tensor::from_data((0..12).collect(), &[2, 2, 3], CpuBackend::new());
+        let b = Tensor::from_data((0..12).collect(), &[2, 3, 2], CpuBackend::new());
+        let out = Tensor::einsum("bij,bjk->bik", &[&a, &b]);
+        assert_eq!(out.shape(), &[2, 2, 2]);
+        for batch in 0..2 {
+            let a_b = Tensor::from_data(
+                a.data()[batch * 6..][..6].to_vec(),
+                &[2, 3],
+                CpuBackend::new(),
+            );
+            let b_b = Tensor::from_data(
+                b.data()[batch * 6..][..6].to_vec(),
+                &[3, 2],
+                CpuBackend::new(),
+            );
+            assert_eq!(&out.data()[batch * 4..][..4], a_b.matmul(&b_b).data());
+        }
+    }
+
+    #[test]
+    fn einsum_reads_strided_operands() {
+        let a = Tensor::from_data((1..=6).collect(), &[3, 2], CpuBackend::new());
+        let b = Tensor::from_data((1..=6).collect(), &[3, 2], CpuBackend::new());
+        let at = a.transpose();
+        assert_eq!(
+            Tensor::einsum("ij,jk->ik", &[&at, &b]),
+            at.contiguous().matmul(&b)
+        );
+    }
+
+    #[test]
+    fn lowered_contractions_match_the_loop() {
+        let t = |shape: &[usize]| {
+            let n = shape.iter().product::<usize>() as i64;
+            Tensor::from_data(
+                (0..n).map(|x| x % 7 - 3).collect(),
+                shape,
+                CpuBackend::new(),
+            )
+        };
+        let cases: [(&str, Vec<Tensor<i64, CpuBackend>>); 9] = [
+            ("ij,jk->ik", vec![t(&[2, 3]), t(&[3, 4])]),
+            ("ij,kj->ki", vec![t(&[2, 3]), t(&[4, 3])]),
+            ("bij,bjk->bik", vec![t(&[3, 2, 5]), t(&[3, 5, 4])]),
+            ("bij,bjk->kbi", vec![t(&[3, 2, 5]), t(&[3, 5, 4])]),
+            ("i,i->", vec![t(&[5]), t(&[5])]),
+            ("i,j->ij", vec![t(&[2]), t(&[3])]),
+            ("ijx,jky->i", vec![t(&[2, 3, 2]), t(&[3, 4, 3])]),
+            ("ijk->kj", vec![t(&[2, 3, 4])]),
+            ("ij,jk", vec![t(&[2, 0]), t(&[0, 3])]),
+        ];
+        for (spec, operands) in &cases {
+            let operands: Vec<&Tensor<i64, CpuBackend>> = operands.iter().collect();
+            let (inputs, output) = parse_spec(spec);
+            let mut labels: Vec<char> = Vec::new();
+            let mut sizes = Vec::new();
+            for (subs, t) in inputs.iter().zip(&operands) {
+                for (&l, &d) in subs.iter().zip(t.shape()) {
+                    if !labels.contains(&l) {
+                        labels.push(l);
+                        sizes.push(d);
+                    }
+                }
+            }
+            let mut expected = einsum_loop(&inputs, &output, &operands, &labels, &sizes);
+            if output.is_empty() {
+                expected = expected.reshape(&[1]);
+            }
+            assert_eq!(Tensor::einsum(spec, &operands), expected, "{spec}");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "einsum label 'j' has size 3 and 2")]
+    fn einsum_rejects_inconsistent_sizes() {
+        let a: Tensor<f32, _> = Tensor::zeros(&[2, 3], CpuBackend::new());
+        let b: Tensor<f32, _> = Tensor::zeros(&[2, 2], CpuBackend::new());
+        Tensor::einsum("ij,jk->ik", &[&a, &b]);
+    }
+
+    #[test]
+    #[should_panic(expected = "names 2 operands, got 1")]
+    fn einsum_rejects_missing_operands() {
+        let a: Tensor<f32, _> = Tensor::zeros(&[2, 2], CpuBackend::new());
+        Tensor::einsum("ij,jk->ik", &[&a]);
+    }
+}
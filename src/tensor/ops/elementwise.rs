@@ -0,0 +1,291 @@
+use std::borrow::Cow;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::tensor::backend::Backend;
+use crate::tensor::error::TensorError;
+use crate::tensor::numeric::{Numeric, Signed};
+use crate::tensor::{Tensor, broadcast_shapes};
+
+impl<T: Numeric, B: Backend<T>> Tensor<T, B> {
+    /// Panics unless `self` and `other` have the same shape.
+    pub(crate) fn assert_same_shape(&self, other: &Self) {
+        assert_eq!(
+            self.shape, other.shape,
+            "shape mismatch: {:?} vs {:?}",
+            self.shape, other.shape
+        );
+    }
+
+    /// The elements of `self` broadcast to `shape`, in logical order.
+    fn broadcast_values(&self, shape: &[usize]) -> Cow<'_, [T]> {
+        if self.shape == shape {
+            self.values()
+        } else {
+            Cow::Owned(self.broadcast_to(shape).values().into_owned())
+        }
+    }
+
+    /// Applies `f` to every element, keeping the shape.
+    pub fn map<F>(&self, f: F) -> Self
+    where
+        F: Fn(T) -> T + Sync,
+    {
+        let data = self.backend.map(&self.values(), f);
+        Tensor::from_data(data, &self.shape, self.backend.clone())
+    }
+
+    /// Combines two same-shaped tensors element by element with `f`.
+    pub fn zip_map<F>(&self, other: &Self, f: F) -> Self
+    where
+        F: Fn(T, T) -> T + Sync,
+    {
+        self.assert_same_shape(other);
+        let data = self.backend.zip_map(&self.values(), &other.values(), f);
+        Tensor::from_data(data, &self.shape, self.backend.clone())
+    }
+
+    /// `self * mul + add` in a single pass, without allocating the
+    /// intermediate product. All three tensors must share a shape.
+    pub fn fma(&self, mul: &Self, add: &Self) -> Self {
+        self.assert_same_shape(mul);
+        self.assert_same_shape(add);
+        let data = self
+            .backend
+            .fma(&self.values(), &mul.values(), &add.values());
+        Tensor::from_data(data, &self.shape, self.backend.clone())
+    }
+
+    /// `self + scale * b` in a single pass, e.g. for scaled residual
+    /// connections.
+    pub fn scaled_add(&self, b: &Self, scale: T) -> Tensor<T, B> {
+        self.assert_same_shape(b);
+        let data = self.backend.scaled_add(&self.values(), &b.values(), scale);
+        Tensor::from_data(data, &self.shape, self.backend.clone())
+    }
+
+    /// Dispatches a binary element-wise operation by name.
+    ///
+    /// Supports `"add"`, `"sub"`, `"mul"`, `"div"`, `"maximum"` and
+    /// `"minimum"`; anything else yields [`TensorError::UnknownOp`].
+    pub fn apply_op(&self, op: &str, other: &Self) -> Result<Tensor<T, B>, TensorError>
+    where
+        T: PartialOrd,
+    {
+        match op {
+            "add" => Ok(self + other),
+            "sub" => Ok(self - other),
+            "mul" => Ok(self * other),
+            "div" => Ok(self / other),
+            "maximum" => Ok(self.maximum(other)),
+            "minimum" => Ok(self.minimum(other)),
+            _ => Err(TensorError::UnknownOp(op.to_string())),
+        }
+    }
+
+    /// Element-wise maximum of two same-shaped tensors.
+    pub fn maximum(&self, other: &Self) -> Self
+    where
+        T: PartialOrd,
+    {
+        self.zip_map(other, |a, b| if b > a { b } else { a })
+    }
+
+    /// Element-wise minimum of two same-shaped tensors.
+    pub fn minimum(&self, other: &Self) -> Self
+    where
+        T: PartialOrd,
+    {
+        self.zip_map(other, |a, b| if b < a { b } else { a })
+    }
+
+    /// Element-wise absolute value.
+    pub fn abs(&self) -> Self
+    where
+        T: Signed,
+    {
+        let data = self.backend.abs(&self.values());
+        Tensor::from_data(data, &self.shape, self.backend.clone())
+    }
+
+    /// Caps every element into `[min, max]`.
+    pub fn clamp(&self, min: T, max: T) -> Self
+    where
+        T: PartialOrd,
+    {
+        assert!(min <= max, "clamp needs min <= max, got {min:?} > {max:?}");
+        let data = self.backend.clamp(&self.values(), min, max);
+        Tensor::from_data(data, &self.shape, self.backend.clone())
+    }
+
+    /// Raises every element below `min` to `min`.
+    pub fn clamp_min(&self, min: T) -> Self
+    where
+        T: PartialOrd,
+    {
+        let data = self.backend.clamp_min(&self.values(), min);
+        Tensor::from_data(data, &self.shape, self.backend.clone())
+    }
+
+    /// Lowers every element above `max` to `max`.
+    pub fn clamp_max(&self, max: T) -> Self
+    where
+        T: PartialOrd,
+    {
+        let data = self.backend.clamp_max(&self.values(), max);
+        Tensor::from_data(data, &self.shape, self.backend.clone())
+    }
+}
+
+// The operands broadcast together under numpy rules, so `&x - &mean` works
+// for a `[2, 3]` x and a `[2, 1]` mean.
+macro_rules! impl_binary_op {
+    ($trait:ident, $method:ident) => {
+        impl<T: Numeric, B: Backend<T>> $trait<&Tensor<T, B>> for &Tensor<T, B> {
+            type Output = Tensor<T, B>;
+
+            fn $method(self, rhs: &Tensor<T, B>) -> Tensor<T, B> {
+                let shape = broadcast_shapes(&self.shape, &rhs.shape).unwrap_or_else(|| {
+                    panic!(
+                        "shapes {:?} and {:?} can't be broadcast together",
+                        self.shape, rhs.shape
+                    )
+                });
+                let data = self.backend.$method(
+                    &self.broadcast_values(&shape),
+                    &rhs.broadcast_values(&shape),
+                );
+                Tensor::from_data(data, &shape, self.backend.clone())
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add);
+impl_binary_op!(Sub, sub);
+impl_binary_op!(Mul, mul);
+impl_binary_op!(Div, div);
+
+impl<T: Numeric + Neg<Output = T>, B: Backend<T>> Neg for &Tensor<T, B> {
+    type Output = Tensor<T, B>;
+
+    fn neg(self) -> Tensor<T, B> {
+        let data = self.backend.neg(&self.values());
+        Tensor::from_data(data, &self.shape, self.backend.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tensor::Tensor;
+    use crate::tensor::backend::CpuBackend;
+    use crate::tensor::error::TensorError;
+
+    fn pair() -> (Tensor<f32, CpuBackend>, Tensor<f32, CpuBackend>) {
+        (
+            Tensor::from_data(vec![1.0, 5.0, 3.0, 8.0], &[2, 2], CpuBackend::new()),
+            Tensor::from_data(vec![4.0, 2.0, 6.0, 7.0], &[2, 2], CpuBackend::new()),
+        )
+    }
+
+    #[test]
+    fn arithmetic_operators() {
+        let (a, b) = pair();
+        assert_eq!((&a + &b).data(), &[5.0, 7.0, 9.0, 15.0]);
+        assert_eq!((&a - &b).data(), &[-3.0, 3.0, -3.0, 1.0]);
+        assert_eq!((&a * &b).data(), &[4.0, 10.0, 18.0, 56.0]);
+        assert_eq!((&b / &a).data(), &[4.0, 0.4, 2.0, 0.875]);
+    }
+
+    #[test]
+    fn integer_addition() {
+        let a = Tensor::from_data(vec![1i32, -2, 3], &[3], CpuBackend::new());
+        let b = Tensor::ones(&[3], CpuBackend::new());
+        assert_eq!((&a + &b).data(), &[2, -1, 4]);
+    }
+
+    #[test]
+    fn negation_and_abs() {
+        let t = Tensor::from_data(vec![1, -2, 3], &[3], CpuBackend::new());
+        assert_eq!((-&t).data(), &[-1, 2, -3]);
+        assert_eq!(t.abs().data(), &[1, 2, 3]);
+
+        let f = Tensor::from_data(vec![-0.5f32, 2.0], &[2], CpuBackend::new());
+        assert_eq!(f.abs().data(), &[0.5, 2.0]);
+    }
+
+    #[test]
+    fn fma_matches_separate_ops() {
+        let (a, b) = pair();
+        let c = Tensor::from_data(vec![0.5, -1.0, 2.0, 0.0], &[2, 2], CpuBackend::new());
+        assert_eq!(a.fma(&b, &c), &(&a * &b) + &c);
+    }
+
+    #[test]
+    fn scaled_add_matches_separate_ops() {
+        let (a, b) = pair();
+        let expected = &a + &b.map(|x| x * 0.5);
+        assert_eq!(a.scaled_add(&b, 0.5), expected);
+        assert_eq!(a.scaled_add(&b, 0.5).data(), &[3.0, 6.0, 6.0, 11.5]);
+    }
+
+    #[test]
+    fn apply_op_dispatches_by_name() {
+        let (a, b) = pair();
+        assert_eq!(
+            a.apply_op("add", &b).unwrap().data(),
+            &[5.0, 7.0, 9.0, 15.0]
+        );
+        assert_eq!(
+            a.apply_op("maximum", &b).unwrap().data(),
+            &[4.0, 5.0, 6.0, 8.0]
+        );
+        assert_eq!(
+            a.apply_op("minimum", &b).unwrap().data(),
+            &[1.0, 2.0, 3.0, 7.0]
+        );
+    }
+
+    #[test]
+    fn apply_op_rejects_unknown_names() {
+        let (a, b) = pair();
+        assert_eq!(
+            a.apply_op("pow", &b).unwrap_err(),
+            TensorError::UnknownOp("pow".to_string())
+        );
+    }
+
+    #[test]
+    fn clamp_into_range() {
+        let t = Tensor::from_data(vec![-2.0, 0.5, 3.0], &[3], CpuBackend::new());
+        assert_eq!(t.clamp(0.0, 1.0).data(), &[0.0, 0.5, 1.0]);
+        assert_eq!(t.clamp_min(0.0).data(), &[0.0, 0.5, 3.0]);
+        assert_eq!(t.clamp_max(1.0).data(), &[-2.0, 0.5, 1.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "min <= max")]
+    fn clamp_rejects_inverted_bounds() {
+        let t = Tensor::from_data(vec![1, 2], &[2], CpuBackend::new());
+        t.clamp(3, 1);
+    }
+
+    #[test]
+    fn arithmetic_operators_broadcast() {
+        let (a, _) = pair();
+        let row = Tensor::from_data(vec![10.0, 20.0], &[2], CpuBackend::new());
+        let column = Tensor::from_data(vec![1.0, 2.0], &[2, 1], CpuBackend::new());
+        assert_eq!((&a + &row).data(), &[11.0, 25.0, 13.0, 28.0]);
+        assert_eq!((&a / &column).data(), &[1.0, 5.0, 1.5, 4.0]);
+        let outer = &row * &column;
+        assert_eq!(outer.shape(), &[2, 2]);
+        assert_eq!(outer.data(), &[10.0, 20.0, 20.0, 40.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "can't be broadcast together")]
+    fn mismatched_shapes_panic() {
+        let a: Tensor<f32, _> = Tensor::zeros(&[2, 2], CpuBackend::new());
+        let b: Tensor<f32, _> = Tensor::zeros(&[4], CpuBackend::new());
+        let _ = &a + &b;
+    }
+}
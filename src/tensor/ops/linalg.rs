@@ -0,0 +1,865 @@
+use crate::tensor::backend::Backend;
+use crate::tensor::error::TensorError;
+use crate::tensor::numeric::{CastFrom, Float, Numeric};
+use crate::tensor::{Tensor, broadcast_shapes};
+
+impl<T: Numeric, B: Backend<T>> Tensor<T, B> {
+    /// Matrix product of two 2D tensors, `[m, k] @ [k, n] -> [m, n]`.
+    pub fn matmul(&self, other: &Self) -> Self {
+        assert_eq!(
+            self.ndim(),
+            2,
+            "matmul expects a 2D lhs, got {:?}",
+            self.shape
+        );
+        assert_eq!(
+            other.ndim(),
+            2,
+            "matmul expects a 2D rhs, got {:?}",
+            other.shape
+        );
+        let (m, k) = (self.shape[0], self.shape[1]);
+        let (k2, n) = (other.shape[0], other.shape[1]);
+        assert_eq!(
+            k, k2,
+            "matmul inner dimensions don't match: {:?} @ {:?}",
+            self.shape, other.shape
+        );
+
+        let data = self
+            .backend
+            .matmul(&self.values(), &other.values(), m, k, n);
+        Tensor::from_data(data, &[m, n], self.backend.clone())
+    }
+
+    /// `out += self @ other`, e.g. to accumulate gradients over several
+    /// products. `out` must already have the `[m, n]` shape of the product.
+    pub fn matmul_add_into(&self, other: &Self, out: &mut Self) {
+        let product = self.matmul(other);
+        assert_eq!(
+            out.shape, product.shape,
+            "matmul_add_into output has shape {:?}, expected {:?}",
+            out.shape, product.shape
+        );
+        if !out.is_contiguous() {
+            *out = out.contiguous();
+        }
+        for (o, &p) in out.data.iter_mut().zip(product.data.iter()) {
+            *o = *o + p;
+        }
+    }
+
+    /// Batched matrix product `[batch, m, k] @ [batch, k, n] -> [batch, m, n]`,
+    /// e.g. for the `[batch * heads, seq, d]` layout of multi-head attention.
+    ///
+    /// The batch entries are independent regularly-strided matmuls, handed to
+    /// [`Backend::bmm`] together; the CPU backend spreads them across worker
+    /// threads.
+    pub fn bmm_strided(&self, other: &Self) -> Self {
+        assert_eq!(
+            self.ndim(),
+            3,
+            "bmm_strided expects a 3D lhs, got {:?}",
+            self.shape
+        );
+        assert_eq!(
+            other.ndim(),
+            3,
+            "bmm_strided expects a 3D rhs, got {:?}",
+            other.shape
+        );
+        let (batch, m, k) = (self.shape[0], self.shape[1], self.shape[2]);
+        let (batch2, k2, n) = (other.shape[0], other.shape[1], other.shape[2]);
+        assert!(
+            batch == batch2 && k == k2,
+            "bmm_strided shapes don't line up: {:?} @ {:?}",
+            self.shape,
+            other.shape
+        );
+
+        let data = self
+            .backend
+            .bmm(&self.values(), &other.values(), batch, m, k, n);
+        Tensor::from_data(data, &[batch, m, n], self.backend.clone())
+    }
+
+    /// Matrix product with numpy's `matmul` semantics.
+    ///
+    /// The last two axes of each operand are the matrices; any leading axes
+    /// are batch dimensions and broadcast against each other, so
+    /// `[1, m, k] @ [b, k, n]` gives `[b, m, n]`. A 1D lhs is treated as a
+    /// row vector and a 1D rhs as a column vector, and the added axis is
+    /// dropped from the result.
+    pub fn matmul_broadcast(&self, other: &Self) -> Self {
+        assert!(
+            self.ndim() >= 1 && other.ndim() >= 1,
+            "matmul_broadcast needs at least 1D operands, got {:?} @ {:?}",
+            self.shape,
+            other.shape
+        );
+        let a = match self.ndim() {
+            1 => self.reshape(&[1, self.shape[0]]),
+            _ => self.clone(),
+        };
+        let b = match other.ndim() {
+            1 => other.reshape(&[other.shape[0], 1]),
+            _ => other.clone(),
+        };
+        let (a_batch, a_mat) = a.shape.split_at(a.ndim() - 2);
+        let (b_batch, b_mat) = b.shape.split_at(b.ndim() - 2);
+        let ((m, k), (k2, n)) = ((a_mat[0], a_mat[1]), (b_mat[0], b_mat[1]));
+        assert_eq!(
+            k, k2,
+            "matmul inner dimensions don't match: {:?} @ {:?}",
+            self.shape, other.shape
+        );
+        let batch = broadcast_shapes(a_batch, b_batch).unwrap_or_else(|| {
+            panic!(
+                "cannot broadcast batch dimensions of {:?} @ {:?}",
+                self.shape, other.shape
+            )
+        });
+        let count: usize = batch.iter().product();
+
+        let stack = |t: &Self, rows: usize, cols: usize| {
+            let full: Vec<usize> = batch.iter().copied().chain([rows, cols]).collect();
+            t.broadcast_to(&full).reshape(&[count, rows, cols])
+        };
+        let product = stack(&a, m, k).bmm_strided(&stack(&b, k, n));
+
+        let mut shape = batch.clone();
+        if self.ndim() > 1 {
+            shape.push(m);
+        }
+        if other.ndim() > 1 {
+            shape.push(n);
+        }
+        product.reshape(&shape)
+    }
+
+    /// Contracts `axes.0` of `self` against `axes.1` of `other`, like numpy's
+    /// `tensordot`.
+    ///
+    /// The result has the remaining axes of `self` followed by the remaining
+    /// axes of `other`. Internally both operands are permuted so the
+    /// contraction becomes a single 2D matmul.
+    pub fn tensordot(&self, other: &Self, axes: (&[usize], &[usize])) -> Self {
+        let (axes_a, axes_b) = axes;
+        assert_eq!(
+            axes_a.len(),
+            axes_b.len(),
+            "tensordot needs the same number of axes on both sides, got {axes_a:?} and {axes_b:?}"
+        );
+        for (&a, &b) in axes_a.iter().zip(axes_b) {
+            assert!(
+                a < self.ndim() && b < other.ndim(),
+                "tensordot axes {axes_a:?}/{axes_b:?} out of range for {:?} and {:?}",
+                self.shape,
+                other.shape
+            );
+            assert_eq!(
+                self.shape[a], other.shape[b],
+                "tensordot axis {a} of {:?} doesn't match axis {b} of {:?}",
+                self.shape, other.shape
+            );
+        }
+
+        let free_a: Vec<usize> = (0..self.ndim()).filter(|i| !axes_a.contains(i)).collect();
+        let free_b: Vec<usize> = (0..other.ndim()).filter(|i| !axes_b.contains(i)).collect();
+        let m: usize = free_a.iter().map(|&i| self.shape[i]).product();
+        let n: usize = free_b.iter().map(|&i| other.shape[i]).product();
+        let k: usize = axes_a.iter().map(|&i| self.shape[i]).product();
+
+        let perm_a: Vec<usize> = free_a.iter().chain(axes_a).copied().collect();
+        let perm_b: Vec<usize> = axes_b.iter().chain(&free_b).copied().collect();
+        let a = self.permute(&perm_a);
+        let b = other.permute(&perm_b);
+        let data = self.backend.matmul(&a.values(), &b.values(), m, k, n);
+
+        let shape: Vec<usize> = free_a
+            .iter()
+            .map(|&i| self.shape[i])
+            .chain(free_b.iter().map(|&i| other.shape[i]))
+            .collect();
+        Tensor::from_data(data, &shape, self.backend.clone())
+    }
+
+    /// Outer product of two 1D tensors: `[m] x [n] -> [m, n]` with
+    /// `out[i][j] = self[i] * other[j]`.
+    pub fn outer(&self, other: &Self) -> Self {
+        assert_eq!(
+            self.ndim(),
+            1,
+            "outer expects a 1D lhs, got {:?}",
+            self.shape
+        );
+        assert_eq!(
+            other.ndim(),
+            1,
+            "outer expects a 1D rhs, got {:?}",
+            other.shape
+        );
+        let (m, n) = (self.shape[0], other.shape[0]);
+        // An [m, 1] @ [1, n] product.
+        let data = self
+            .backend
+            .matmul(&self.values(), &other.values(), m, 1, n);
+        Tensor::from_data(data, &[m, n], self.backend.clone())
+    }
+
+    /// Gram matrix `X @ Xᵀ` of a 2D `[features, samples]` tensor, giving the
+    /// `[features, features]` inner products between rows.
+    pub fn gram(&self) -> Self {
+        assert_eq!(
+            self.ndim(),
+            2,
+            "gram expects a 2D tensor, got {:?}",
+            self.shape
+        );
+        self.matmul(&self.transpose())
+    }
+
+    /// Main diagonal of a 2D tensor, `min(rows, cols)` elements long.
+    pub fn diag(&self) -> Self {
+        assert_eq!(
+            self.ndim(),
+            2,
+            "diag expects a 2D tensor, got {:?}",
+            self.shape
+        );
+        let len = self.shape[0].min(self.shape[1]);
+        let data = (0..len).map(|i| self.get(&[i, i])).collect();
+        Tensor::from_data(data, &[len], self.backend.clone())
+    }
+
+    /// Sum of the main diagonal of a square 2D tensor.
+    pub fn trace(&self) -> T {
+        assert!(
+            self.ndim() == 2 && self.shape[0] == self.shape[1],
+            "trace expects a square matrix, got {:?}",
+            self.shape
+        );
+        (0..self.shape[0]).fold(T::zero(), |acc, i| acc + self.get(&[i, i]))
+    }
+
+    /// Square matrix with the elements of a 1D tensor on its main diagonal.
+    pub fn diag_embed(&self) -> Self {
+        assert_eq!(
+            self.ndim(),
+            1,
+            "diag_embed expects a 1D tensor, got {:?}",
+            self.shape
+        );
+        let n = self.shape[0];
+        let mut out = Self::zeros(&[n, n], self.backend.clone());
+        for (i, x) in self.values().iter().enumerate() {
+            out.set(&[i, i], *x);
+        }
+        out
+    }
+
+    /// Upper triangle of a 2D tensor: elements below diagonal `diagonal` are
+    /// zeroed. `0` is the main diagonal, positive offsets move up and right,
+    /// negative ones down and left.
+    pub fn triu(&self, diagonal: isize) -> Self {
+        self.keep_triangle("triu", |i, j| j - i >= diagonal)
+    }
+
+    /// Lower triangle of a 2D tensor: elements above diagonal `diagonal` are
+    /// zeroed, with offsets as in [`Tensor::triu`].
+    pub fn tril(&self, diagonal: isize) -> Self {
+        self.keep_triangle("tril", |i, j| j - i <= diagonal)
+    }
+
+    /// Zeroes the elements at `(row, col)` for which `keep` is false.
+    fn keep_triangle<F: Fn(isize, isize) -> bool>(&self, op: &str, keep: F) -> Self {
+        assert_eq!(
+            self.ndim(),
+            2,
+            "{op} expects a 2D tensor, got {:?}",
+            self.shape
+        );
+        let cols = self.shape[1];
+        let data = self
+            .values()
+            .iter()
+            .enumerate()
+            .map(|(at, &x)| {
+                let (i, j) = ((at / cols) as isize, (at % cols) as isize);
+                if keep(i, j) { x } else { T::zero() }
+            })
+            .collect();
+        Tensor::from_data(data, &self.shape, self.backend.clone())
+    }
+}
+
+impl<T: Float, B: Backend<T>> Tensor<T, B> {
+    /// Cosine similarity along `axis`: `x . y / max(|x| * |y|, eps)`.
+    ///
+    /// `eps` keeps the result finite for zero vectors.
+    pub fn cosine_similarity(&self, other: &Self, axis: usize, eps: T) -> Self {
+        let dot = (self * other).sum(Some(axis));
+        let norms = &(self * self).sum(Some(axis)) * &(other * other).sum(Some(axis));
+        let denom = norms.map(|n| {
+            let n = n.sqrt();
+            if n > eps { n } else { eps }
+        });
+        &dot / &denom
+    }
+
+    /// Inverse of a square 2D matrix by Gauss-Jordan elimination with
+    /// partial pivoting.
+    ///
+    /// Returns [`TensorError::Singular`] when a pivot is negligible relative
+    /// to the largest entry, i.e. the matrix is singular to working precision.
+    pub fn inverse(&self) -> Result<Self, TensorError>
+    where
+        T: CastFrom<usize>,
+    {
+        let n = self.square_dim("inverse");
+        let mut a = self.values().into_owned();
+        let mut inv = Self::eye(n, self.backend.clone()).data;
+        let tolerance = self.singular_tolerance(&a);
+
+        for col in 0..n {
+            let pivot = pivot_row(&a, n, col, tolerance)?;
+            swap_rows(&mut a, n, col, pivot);
+            swap_rows(&mut inv, n, col, pivot);
+
+            let scale = T::one() / a[col * n + col];
+            for j in 0..n {
+                a[col * n + j] = a[col * n + j] * scale;
+                inv[col * n + j] = inv[col * n + j] * scale;
+            }
+            for row in (0..n).filter(|&r| r != col) {
+                let factor = a[row * n + col];
+                if factor == T::zero() {
+                    continue;
+                }
+                for j in 0..n {
+                    a[row * n + j] = a[row * n + j] - factor * a[col * n + j];
+                    inv[row * n + j] = inv[row * n + j] - factor * inv[col * n + j];
+                }
+            }
+        }
+        Ok(Tensor::from_data(inv, &[n, n], self.backend.clone()))
+    }
+
+    /// LU factorization with partial pivoting, `P A = L U`.
+    ///
+    /// The factors come back packed in one `[n, n]` matrix: `U` on and above
+    /// the diagonal and `L` below it, with `L`'s unit diagonal left implicit.
+    /// `perm[i]` is the row of `self` that ended up in row `i`.
+    pub fn lu(&self) -> Result<(Self, Vec<usize>), TensorError>
+    where
+        T: CastFrom<usize>,
+    {
+        let n = self.square_dim("lu");
+        let mut a = self.values().into_owned();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let tolerance = self.singular_tolerance(&a);
+
+        for col in 0..n {
+            let pivot = pivot_row(&a, n, col, tolerance)?;
+            swap_rows(&mut a, n, col, pivot);
+            perm.swap(col, pivot);
+            for row in col + 1..n {
+                let factor = a[row * n + col] / a[col * n + col];
+                a[row * n + col] = factor;
+                for j in col + 1..n {
+                    a[row * n + j] = a[row * n + j] - factor * a[col * n + j];
+                }
+            }
+        }
+        Ok((Tensor::from_data(a, &[n, n], self.backend.clone()), perm))
+    }
+
+    /// Solves `self @ x = b` through [`Tensor::lu`], for a right-hand side
+    /// `b` of shape `[n]` or `[n, k]`; `x` has the shape of `b`.
+    pub fn solve(&self, b: &Self) -> Result<Self, TensorError>
+    where
+        T: CastFrom<usize>,
+    {
+        let n = self.square_dim("solve");
+        assert!(
+            matches!(b.ndim(), 1 | 2) && b.shape[0] == n,
+            "solve expects a right-hand side of shape [{n}] or [{n}, k], got {:?}",
+            b.shape
+        );
+        let k = if b.ndim() == 2 { b.shape[1] } else { 1 };
+        let (lu, perm) = self.lu()?;
+        let lu = lu.data;
+        let rhs = b.values();
+
+        let mut x = vec![T::zero(); n * k];
+        for c in 0..k {
+            // Forward substitution with the unit lower triangle on P b.
+            for i in 0..n {
+                let mut acc = rhs[perm[i] * k + c];
+                for j in 0..i {
+                    acc = acc - lu[i * n + j] * x[j * k + c];
+                }
+                x[i * k + c] = acc;
+            }
+            // Back substitution with the upper triangle.
+            for i in (0..n).rev() {
+                let mut acc = x[i * k + c];
+                for j in i + 1..n {
+                    acc = acc - lu[i * n + j] * x[j * k + c];
+                }
+                x[i * k + c] = acc / lu[i * n + i];
+            }
+        }
+        Ok(Tensor::from_data(x, &b.shape, self.backend.clone()))
+    }
+
+    /// Cholesky factor of a symmetric positive-definite matrix: the lower
+    /// triangular `L` with `L @ L^T == self`.
+    ///
+    /// Only the lower triangle of `self` is read; symmetry is assumed, not
+    /// checked. A pivot that isn't strictly positive yields
+    /// [`TensorError::NotPositiveDefinite`].
+    pub fn cholesky(&self) -> Result<Self, TensorError> {
+        let n = self.square_dim("cholesky");
+        let a = self.values();
+        let mut l = vec![T::zero(); n * n];
+        for j in 0..n {
+            let diag = (0..j).fold(a[j * n + j], |acc, k| acc - l[j * n + k] * l[j * n + k]);
+            if diag <= T::zero() || !diag.is_finite() {
+                return Err(TensorError::NotPositiveDefinite);
+            }
+            let pivot = diag.sqrt();
+            l[j * n + j] = pivot;
+            for i in j + 1..n {
+                let dot = (0..j).fold(a[i * n + j], |acc, k| acc - l[i * n + k] * l[j * n + k]);
+                l[i * n + j] = dot / pivot;
+            }
+        }
+        Ok(Tensor::from_data(l, &[n, n], self.backend.clone()))
+    }
+
+    /// Side length of a square 2D tensor; panics naming `op` otherwise.
+    fn square_dim(&self, op: &str) -> usize {
+        assert!(
+            self.ndim() == 2 && self.shape[0] == self.shape[1],
+            "{op} expects a square matrix, got {:?}",
+            self.shape
+        );
+        self.shape[0]
+    }
+
+    /// Pivots at or below this size are treated as zero.
+    fn singular_tolerance(&self, a: &[T]) -> T
+    where
+        T: CastFrom<usize>,
+    {
+        let largest = a
+            .iter()
+            .fold(T::zero(), |m, &x| if x.abs() > m { x.abs() } else { m });
+        largest * T::epsilon() * T::cast_from(self.shape[0])
+    }
+}
+
+/// Row at or below `col` with the largest entry in column `col` of the
+/// row-major `[n, n]` matrix `a`, or [`TensorError::Singular`] if even that
+/// entry is within `tolerance` of zero.
+fn pivot_row<T: Float>(a: &[T], n: usize, col: usize, tolerance: T) -> Result<usize, TensorError> {
+    let pivot = (col..n)
+        .max_by(|&i, &j| {
+            let (x, y) = (a[i * n + col].abs(), a[j * n + col].abs());
+            x.partial_cmp(&y).unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .unwrap();
+    let size = a[pivot * n + col].abs();
+    if size <= tolerance || size.is_nan() {
+        return Err(TensorError::Singular);
+    }
+    Ok(pivot)
+}
+
+fn swap_rows<T>(data: &mut [T], n: usize, a: usize, b: usize) {
+    if a != b {
+        for j in 0..n {
+            data.swap(a * n + j, b * n + j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tensor::Tensor;
+    use crate::tensor::backend::CpuBackend;
+    use crate::tensor::error::TensorError;
+
+    #[test]
+    fn matmul_2x3_by_3x2() {
+        let a = Tensor::from_data(
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+            &[2, 3],
+            CpuBackend::new(),
+        );
+        let b = Tensor::from_data(
+            vec![7.0, 8.0, 9.0, 10.0, 11.0, 12.0],
+            &[3, 2],
+            CpuBackend::new(),
+        );
+        let c = a.matmul(&b);
+        assert_eq!(c.shape(), &[2, 2]);
+        assert_eq!(c.data(), &[58.0, 64.0, 139.0, 154.0]);
+    }
+
+    #[test]
+    fn matmul_broadcast_stretches_a_batch_of_one() {
+        let a = Tensor::from_data(vec![1, 2, 3, 4], &[1, 2, 2], CpuBackend::new());
+        let b = Tensor::from_fn(
+            &[3, 2, 2],
+            |i| (i[0] * 4 + i[1] * 2 + i[2]) as i32,
+            CpuBackend::new(),
+        );
+        let out = a.matmul_broadcast(&b);
+        assert_eq!(out.shape(), &[3, 2, 2]);
+        let a2 = Tensor::from_data(vec![1, 2, 3, 4], &[2, 2], CpuBackend::new());
+        for i in 0..3 {
+            let bi = b.slice_axis(0, i, 1).reshape(&[2, 2]);
+            assert_eq!(out.slice_axis(0, i, 1).reshape(&[2, 2]), a2.matmul(&bi));
+        }
+    }
+
+    #[test]
+    fn matmul_broadcast_with_vectors() {
+        let v = Tensor::from_data(vec![1, 2], &[2], CpuBackend::new());
+        let m = Tensor::from_data(vec![1, 2, 3, 4, 5, 6], &[2, 3], CpuBackend::new());
+        let row = v.matmul_broadcast(&m);
+        assert_eq!(row.shape(), &[3]);
+        assert_eq!(row.data(), &[9, 12, 15]);
+
+        let col = m.transpose().matmul_broadcast(&v);
+        assert_eq!(col.shape(), &[3]);
+        assert_eq!(col.data(), &[9, 12, 15]);
+
+        assert_eq!(v.matmul_broadcast(&v).data(), &[5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot broadcast batch")]
+    fn matmul_broadcast_rejects_mismatched_batches() {
+        let a: Tensor<f32, _> = Tensor::zeros(&[2, 1, 1], CpuBackend::new());
+        let b: Tensor<f32, _> = Tensor::zeros(&[3, 1, 1], CpuBackend::new());
+        a.matmul_broadcast(&b);
+    }
+
+    #[test]
+    fn matmul_add_into_accumulates_products() {
+        let a = Tensor::from_data(vec![1, 2, 3, 4], &[2, 2], CpuBackend::new());
+        let b = Tensor::from_data(vec![5, 6, 7, 8], &[2, 2], CpuBackend::new());
+        let mut out = Tensor::zeros(&[2, 2], CpuBackend::new());
+        a.matmul_add_into(&b, &mut out);
+        b.matmul_add_into(&a, &mut out);
+        assert_eq!(out, &a.matmul(&b) + &b.matmul(&a));
+        assert_eq!(out.data(), &[42, 56, 74, 96]);
+    }
+
+    #[test]
+    fn matmul_on_integers() {
+        let a = Tensor::from_data(vec![1i64, -2, 3, 4, 0, -6], &[2, 3], CpuBackend::new());
+        let b = Tensor::from_data(vec![7i64, 8, 9, 10, -11, 12], &[3, 2], CpuBackend::new());
+        // [1*7 - 2*9 - 3*11, 1*8 - 2*10 + 3*12], [4*7 + 6*11, 4*8 - 6*12]
+        assert_eq!(a.matmul(&b).data(), &[-44, 24, 94, -40]);
+
+        let ones: Tensor<i64, _> = Tensor::ones(&[3, 1], CpuBackend::new());
+        assert_eq!(a.matmul(&ones).data(), &[2, -2]);
+    }
+
+    #[test]
+    fn matmul_respects_transposed_views() {
+        let a = Tensor::from_data(vec![1.0, 2.0, 3.0, 4.0], &[2, 2], CpuBackend::new());
+        let c = a.transpose().matmul(&a);
+        assert_eq!(c.data(), &[10.0, 14.0, 14.0, 20.0]);
+    }
+
+    #[test]
+    fn bmm_strided_matches_per_batch_matmul() {
+        let (batch, seq, d) = (8, 3, 4);
+        let q = Tensor::from_data(
+            (0..batch * seq * d).map(|x| (x % 7) as f64 - 3.0).collect(),
+            &[batch, seq, d],
+            CpuBackend::new(),
+        );
+        let kt = Tensor::from_data(
+            (0..batch * d * seq).map(|x| (x % 5) as f64 * 0.5).collect(),
+            &[batch, d, seq],
+            CpuBackend::new(),
+        );
+        let scores = q.bmm_strided(&kt);
+        assert_eq!(scores.shape(), &[batch, seq, seq]);
+
+        for b in 0..batch {
+            let qb = Tensor::from_data(
+                q.data()[b * seq * d..(b + 1) * seq * d].to_vec(),
+                &[seq, d],
+                CpuBackend::new(),
+            );
+            let kb = Tensor::from_data(
+                kt.data()[b * d * seq..(b + 1) * d * seq].to_vec(),
+                &[d, seq],
+                CpuBackend::new(),
+            );
+            assert_eq!(
+                &scores.data()[b * seq * seq..(b + 1) * seq * seq],
+                qb.matmul(&kb).data()
+            );
+        }
+    }
+
+    #[test]
+    fn tensordot_last_with_first_axis() {
+        let a = Tensor::from_data(
+            (0..24).map(|x| x as f64).collect(),
+            &[2, 3, 4],
+            CpuBackend::new(),
+        );
+        let b = Tensor::from_data(
+            (0..20).map(|x| x as f64 * 0.5).collect(),
+            &[4, 5],
+            CpuBackend::new(),
+        );
+        let c = a.tensordot(&b, (&[2], &[0]));
+        assert_eq!(c.shape(), &[2, 3, 5]);
+
+        let expected = a.reshape(&[6, 4]).matmul(&b).reshape(&[2, 3, 5]);
+        assert_eq!(c, expected);
+    }
+
+    #[test]
+    fn tensordot_over_all_axes() {
+        let a = Tensor::from_data(vec![1, 2, 3, 4, 5, 6], &[2, 3], CpuBackend::new());
+        let b = Tensor::from_data(vec![1, 2, 3, 4, 5, 6], &[3, 2], CpuBackend::new());
+        // sum_ij a[i][j] * b[j][i] is the trace of a @ b.
+        let c = a.tensordot(&b, (&[0, 1], &[1, 0]));
+        assert_eq!(c.shape(), &[] as &[usize]);
+        assert_eq!(c.data(), &[22 + 64]);
+    }
+
+    #[test]
+    #[should_panic(expected = "doesn't match")]
+    fn tensordot_rejects_mismatched_lengths() {
+        let a: Tensor<f32, _> = Tensor::zeros(&[2, 3], CpuBackend::new());
+        a.tensordot(&a, (&[1], &[0]));
+    }
+
+    #[test]
+    fn cosine_similarity_of_vector_with_itself_and_negation() {
+        let v = Tensor::from_data(vec![1.0f64, -2.0, 3.0], &[3], CpuBackend::new());
+        let neg = v.map(|x| -x);
+        assert!((v.cosine_similarity(&v, 0, 1e-8).data()[0] - 1.0).abs() < 1e-12);
+        assert!((v.cosine_similarity(&neg, 0, 1e-8).data()[0] + 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn cosine_similarity_per_row() {
+        let a = Tensor::from_data(vec![1.0f32, 0.0, 0.0, 0.0], &[2, 2], CpuBackend::new());
+        let b = Tensor::from_data(vec![0.0f32, 1.0, 1.0, 1.0], &[2, 2], CpuBackend::new());
+        // Orthogonal first row; the zero second row is kept finite by eps.
+        assert_eq!(a.cosine_similarity(&b, 1, 1e-6).data(), &[0.0, 0.0]);
+    }
+
+    #[test]
+    fn outer_product() {
+        let a = Tensor::from_data(vec![1, 2], &[2], CpuBackend::new());
+        let b = Tensor::from_data(vec![3, 4, 5], &[3], CpuBackend::new());
+        let o = a.outer(&b);
+        assert_eq!(o.shape(), &[2, 3]);
+        assert_eq!(o.data(), &[3, 4, 5, 6, 8, 10]);
+    }
+
+    #[test]
+    fn gram_matrix_of_small_input() {
+        let x = Tensor::from_data(vec![1, 2, 3, 4, 5, 6], &[2, 3], CpuBackend::new());
+        let g = x.gram();
+        assert_eq!(g.shape(), &[2, 2]);
+        // [1·1+2·2+3·3, 1·4+2·5+3·6; ..., 4·4+5·5+6·6]
+        assert_eq!(g.data(), &[14, 32, 32, 77]);
+    }
+
+    #[test]
+    fn diag_round_trips_through_diag_embed() {
+        let m = Tensor::from_data((1..=9).collect(), &[3, 3], CpuBackend::new());
+        let d = m.diag();
+        assert_eq!(d.shape(), &[3]);
+        assert_eq!(d.data(), &[1, 5, 9]);
+
+        let e = d.diag_embed();
+        assert_eq!(e.shape(), &[3, 3]);
+        assert_eq!(e.data(), &[1, 0, 0, 0, 5, 0, 0, 0, 9]);
+        assert_eq!(e.diag(), d);
+    }
+
+    #[test]
+    fn inverse_of_known_matrices() {
+        let m = Tensor::from_data(vec![4.0, 7.0, 2.0, 6.0], &[2, 2], CpuBackend::new());
+        let inv = m.inverse().unwrap();
+        let expected = Tensor::from_data(vec![0.6, -0.7, -0.2, 0.4], &[2, 2], CpuBackend::new());
+        assert!(inv.allclose(&expected, 1e-12, 0.0));
+        assert!(
+            m.matmul(&inv)
+                .allclose(&Tensor::eye(2, CpuBackend::new()), 1e-12, 0.0)
+        );
+
+        // Needs a row swap: the top-left entry is zero.
+        let m = Tensor::from_data(
+            vec![0.0, 2.0, 1.0, 1.0, 1.0, 0.0, 3.0, 0.0, 1.0],
+            &[3, 3],
+            CpuBackend::new(),
+        );
+        let inv = m.inverse().unwrap();
+        assert!(
+            m.matmul(&inv)
+                .allclose(&Tensor::eye(3, CpuBackend::new()), 1e-12, 0.0)
+        );
+    }
+
+    #[test]
+    fn inverse_reports_singular_matrices() {
+        let m = Tensor::from_data(
+            (1..=9).map(|x| x as f64).collect(),
+            &[3, 3],
+            CpuBackend::new(),
+        );
+        assert_eq!(m.inverse().unwrap_err(), TensorError::Singular);
+        let zero: Tensor<f32, _> = Tensor::zeros(&[2, 2], CpuBackend::new());
+        assert_eq!(zero.inverse().unwrap_err(), TensorError::Singular);
+    }
+
+    #[test]
+    fn lu_reconstructs_the_permuted_matrix() {
+        let m = Tensor::from_data(
+            vec![2.0, 1.0, 1.0, 4.0, -6.0, 0.0, -2.0, 7.0, 2.0],
+            &[3, 3],
+            CpuBackend::new(),
+        );
+        let (lu, perm) = m.lu().unwrap();
+        let (mut l, mut u) = (
+            Tensor::eye(3, CpuBackend::new()),
+            Tensor::zeros(&[3, 3], CpuBackend::new()),
+        );
+        for i in 0..3 {
+            for j in 0..3 {
+                if j < i {
+                    l.set(&[i, j], lu.get(&[i, j]));
+                } else {
+                    u.set(&[i, j], lu.get(&[i, j]));
+                }
+            }
+        }
+        let permuted = m.gather(0, &Tensor::from_data(perm, &[3], CpuBackend::new()));
+        assert!(l.matmul(&u).allclose(&permuted, 1e-12, 0.0));
+    }
+
+    #[test]
+    fn solve_vector_and_matrix_right_hand_sides() {
+        // x = [1, 2, 3]
+        let a = Tensor::from_data(
+            vec![2.0, 1.0, 1.0, 4.0, -6.0, 0.0, -2.0, 7.0, 2.0],
+            &[3, 3],
+            CpuBackend::new(),
+        );
+        let b = Tensor::from_data(vec![7.0, -8.0, 18.0], &[3], CpuBackend::new());
+        let x = a.solve(&b).unwrap();
+        assert_eq!(x.shape(), &[3]);
+        assert!(x.allclose(
+            &Tensor::from_data(vec![1.0, 2.0, 3.0], &[3], CpuBackend::new()),
+            1e-12,
+            0.0
+        ));
+
+        let xs = Tensor::from_data(
+            vec![1.0, 0.0, 2.0, 1.0, 3.0, -1.0],
+            &[3, 2],
+            CpuBackend::new(),
+        );
+        let solved = a.solve(&a.matmul(&xs)).unwrap();
+        assert!(solved.allclose(&xs, 1e-12, 0.0));
+
+        let singular = Tensor::from_data(vec![1.0, 2.0, 2.0, 4.0], &[2, 2], CpuBackend::new());
+        let rhs = Tensor::from_data(vec![1.0, 1.0], &[2], CpuBackend::new());
+        assert_eq!(singular.solve(&rhs).unwrap_err(), TensorError::Singular);
+    }
+
+    #[test]
+    fn cholesky_reconstructs_an_spd_matrix() {
+        #[rustfmt::skip]
+        let a = Tensor::from_data(vec![
+            4.0, 12.0, -16.0,
+            12.0, 37.0, -43.0,
+            -16.0, -43.0, 98.0,
+        ], &[3, 3], CpuBackend::new());
+        let l = a.cholesky().unwrap();
+        assert_eq!(l.data(), &[2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0]);
+        assert!(l.matmul(&l.transpose()).allclose(&a, 1e-12, 1e-12));
+    }
+
+    #[test]
+    fn cholesky_rejects_indefinite_matrices() {
+        let a = Tensor::from_data(vec![1.0, 2.0, 2.0, 1.0], &[2, 2], CpuBackend::new());
+        assert_eq!(a.cholesky().unwrap_err(), TensorError::NotPositiveDefinite);
+    }
+
+    #[test]
+    fn trace_sums_the_diagonal() {
+        let m = Tensor::from_data((1..=9).collect(), &[3, 3], CpuBackend::new());
+        assert_eq!(m.trace(), m.get(&[0, 0]) + m.get(&[1, 1]) + m.get(&[2, 2]));
+        assert_eq!(m.trace(), 15);
+    }
+
+    #[test]
+    #[should_panic(expected = "square matrix")]
+    fn trace_rejects_non_square() {
+        let m: Tensor<f32, _> = Tensor::zeros(&[2, 3], CpuBackend::new());
+        m.trace();
+    }
+
+    #[test]
+    fn diag_of_non_square_matrix() {
+        let m = Tensor::from_data((1..=6).collect(), &[2, 3], CpuBackend::new());
+        assert_eq!(m.diag().data(), &[1, 5]);
+        assert_eq!(m.transpose().diag().data(), &[1, 5]);
+    }
+
+    #[test]
+    fn triangles_on_and_above_the_main_diagonal() {
+        let m = Tensor::from_data((1..=9).collect(), &[3, 3], CpuBackend::new());
+        assert_eq!(m.triu(0).data(), &[1, 2, 3, 0, 5, 6, 0, 0, 9]);
+        assert_eq!(m.tril(0).data(), &[1, 0, 0, 4, 5, 0, 7, 8, 9]);
+        assert_eq!(m.triu(1).data(), &[0, 2, 3, 0, 0, 6, 0, 0, 0]);
+        assert_eq!(m.tril(1).data(), &[1, 2, 0, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn triangles_with_negative_offsets() {
+        let m = Tensor::from_data((1..=6).collect(), &[3, 2], CpuBackend::new());
+        assert_eq!(m.triu(-1).data(), &[1, 2, 3, 4, 0, 6]);
+        assert_eq!(m.tril(-1).data(), &[0, 0, 3, 0, 5, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "triu expects a 2D tensor")]
+    fn triu_rejects_vectors() {
+        let v: Tensor<f32, _> = Tensor::zeros(&[3], CpuBackend::new());
+        v.triu(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "1D lhs")]
+    fn outer_rejects_matrices() {
+        let a: Tensor<f32, _> = Tensor::zeros(&[2, 2], CpuBackend::new());
+        let b: Tensor<f32, _> = Tensor::zeros(&[2], CpuBackend::new());
+        a.outer(&b);
+    }
+
+    #[test]
+    #[should_panic(expected = "inner dimensions")]
+    fn matmul_rejects_mismatched_inner_dims() {
+        let a: Tensor<f32, _> = Tensor::zeros(&[2, 3], CpuBackend::new());
+        a.matmul(&a);
+    }
+}
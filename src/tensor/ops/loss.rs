@@ -0,0 +1,266 @@
+//! Loss functions and divergences.
+
+use crate::tensor::Tensor;
+use crate::tensor::backend::Backend;
+use crate::tensor::numeric::{CastFrom, Float};
+
+/// How an element-wise loss is combined into a scalar. The per-element
+/// losses themselves come from the `*_elementwise` variants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reduction {
+    /// Mean over all elements.
+    Mean,
+    /// Sum over all elements.
+    Sum,
+}
+
+fn reduce_loss<T, B>(losses: Tensor<T, B>, reduction: Reduction) -> T
+where
+    T: Float + CastFrom<usize>,
+    B: Backend<T>,
+{
+    let reduced = match reduction {
+        Reduction::Mean => losses.mean(None),
+        Reduction::Sum => losses.sum(None),
+    };
+    reduced.data()[0]
+}
+
+/// Squared error `(pred - target)^2`, reduced according to `reduction`.
+pub fn mse_loss<T, B>(pred: &Tensor<T, B>, target: &Tensor<T, B>, reduction: Reduction) -> T
+where
+    T: Float + CastFrom<usize>,
+    B: Backend<T>,
+{
+    reduce_loss(mse_loss_elementwise(pred, target), reduction)
+}
+
+/// The per-element squared errors behind [`mse_loss`], shaped like the
+/// inputs.
+pub fn mse_loss_elementwise<T: Float, B: Backend<T>>(
+    pred: &Tensor<T, B>,
+    target: &Tensor<T, B>,
+) -> Tensor<T, B> {
+    pred.zip_map(target, |p, t| (p - t) * (p - t))
+}
+
+/// Absolute error `|pred - target|`, reduced according to `reduction`.
+pub fn l1_loss<T, B>(pred: &Tensor<T, B>, target: &Tensor<T, B>, reduction: Reduction) -> T
+where
+    T: Float + CastFrom<usize>,
+    B: Backend<T>,
+{
+    reduce_loss(l1_loss_elementwise(pred, target), reduction)
+}
+
+/// The per-element absolute errors behind [`l1_loss`], shaped like the
+/// inputs.
+pub fn l1_loss_elementwise<T: Float, B: Backend<T>>(
+    pred: &Tensor<T, B>,
+    target: &Tensor<T, B>,
+) -> Tensor<T, B> {
+    pred.zip_map(target, |p, t| (p - t).abs())
+}
+
+/// Kullback-Leibler divergence `sum(p * (ln p - ln q))` along `axis`.
+///
+/// `p` and `q` hold probabilities of the same shape. Entries where `p` is
+/// zero contribute nothing, following the `0 * ln 0 = 0` convention.
+pub fn kl_div<T: Float, B: Backend<T>>(
+    p: &Tensor<T, B>,
+    q: &Tensor<T, B>,
+    axis: usize,
+) -> Tensor<T, B> {
+    let terms = p.zip_map(q, |p, q| {
+        if p == T::zero() {
+            T::zero()
+        } else {
+            p * (p.ln() - q.ln())
+        }
+    });
+    terms.reduce_with(Some(axis), |lane| {
+        lane.iter().fold(T::zero(), |acc, &x| acc + x)
+    })
+}
+
+/// Huber loss averaged over all elements: `0.5 * r^2` for residuals
+/// `|r| <= delta`, and `delta * (|r| - 0.5 * delta)` beyond that.
+pub fn huber<T, B>(pred: &Tensor<T, B>, target: &Tensor<T, B>, delta: T) -> T
+where
+    T: Float + CastFrom<usize>,
+    B: Backend<T>,
+{
+    let half = T::one() / T::from(2);
+    let losses = pred.zip_map(target, |p, t| {
+        let r = (p - t).abs();
+        if r <= delta {
+            half * r * r
+        } else {
+            delta * (r - half * delta)
+        }
+    });
+    losses.sum(None).data()[0] / T::cast_from(losses.numel())
+}
+
+/// Mean negative log-likelihood of `targets` under `softmax(logits)` along
+/// the last axis.
+///
+/// `logits` is `[..., classes]` and `targets` holds one class index per
+/// lane, with the leading shape of `logits`. The log-softmax is computed as
+/// `x - max - ln(sum(exp(x - max)))` so large logits do not overflow.
+pub fn cross_entropy<T, B>(logits: &Tensor<T, B>, targets: &Tensor<usize, B>) -> T
+where
+    T: Float + CastFrom<usize>,
+    B: Backend<T>,
+{
+    assert!(
+        logits.ndim() > 0 && targets.shape() == &logits.shape()[..logits.ndim() - 1],
+        "cross_entropy expects targets of shape {:?}, got {:?}",
+        logits
+            .shape()
+            .split_last()
+            .map_or(&[][..], |(_, lead)| lead),
+        targets.shape()
+    );
+    let classes = logits.shape()[logits.ndim() - 1];
+    let logits = logits.values();
+    let targets = targets.values();
+    let total =
+        logits
+            .chunks(classes.max(1))
+            .zip(targets.iter())
+            .fold(T::zero(), |acc, (row, &target)| {
+                assert!(
+                    target < classes,
+                    "target class {target} out of range for {classes} classes"
+                );
+                let max = row.iter().fold(row[0], |m, &x| if x > m { x } else { m });
+                let sum = row.iter().fold(T::zero(), |s, &x| s + (x - max).exp());
+                acc + (max + sum.ln() - row[target])
+            });
+    total / T::cast_from(targets.len())
+}
+
+/// Triplet margin loss over a batch of `[batch, dim]` embeddings:
+/// `max(0, |a - p| - |a - n| + margin)` with Euclidean distances, averaged
+/// over the batch.
+pub fn triplet_margin<T, B>(
+    anchor: &Tensor<T, B>,
+    positive: &Tensor<T, B>,
+    negative: &Tensor<T, B>,
+    margin: T,
+) -> T
+where
+    T: Float + CastFrom<usize>,
+    B: Backend<T>,
+{
+    assert_eq!(
+        anchor.ndim(),
+        2,
+        "triplet_margin expects [batch, dim] embeddings, got {:?}",
+        anchor.shape()
+    );
+    let distance = |other: &Tensor<T, B>| {
+        let diff = anchor - other;
+        (&diff * &diff).sum(Some(1)).sqrt()
+    };
+    let losses = distance(positive).zip_map(&distance(negative), |dp, dn| {
+        let l = dp - dn + margin;
+        if l > T::zero() { l } else { T::zero() }
+    });
+    losses.sum(None).data()[0] / T::cast_from(losses.numel())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::backend::CpuBackend;
+
+    #[test]
+    fn kl_of_a_distribution_with_itself_is_zero() {
+        let p = Tensor::from_data(
+            vec![0.2, 0.5, 0.3, 0.0, 0.9, 0.1],
+            &[2, 3],
+            CpuBackend::new(),
+        );
+        let kl = kl_div(&p, &p, 1);
+        assert_eq!(kl.shape(), &[2]);
+        assert!(kl.data().iter().all(|x: &f64| x.abs() < 1e-12));
+    }
+
+    #[test]
+    fn huber_mixes_quadratic_and_linear_regions() {
+        let pred = Tensor::from_data(vec![0.5f64, 3.0, -1.0, 2.0], &[4], CpuBackend::new());
+        let target = Tensor::from_data(vec![0.0f64, 0.0, 0.0, 2.0], &[4], CpuBackend::new());
+        // residuals 0.5, 3, 1, 0 with delta 1: 0.125, 2.5, 0.5, 0
+        let loss = huber(&pred, &target, 1.0);
+        assert!((loss - 3.125 / 4.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn triplet_margin_easy_and_hard_triplets() {
+        let anchor = Tensor::from_data(vec![0.0f64, 0.0], &[1, 2], CpuBackend::new());
+        let near = Tensor::from_data(vec![1.0f64, 0.0], &[1, 2], CpuBackend::new());
+        let far = Tensor::from_data(vec![3.0f64, 4.0], &[1, 2], CpuBackend::new());
+        // d(a, near) = 1, d(a, far) = 5
+        assert_eq!(triplet_margin(&anchor, &near, &far, 1.0), 0.0);
+        assert_eq!(triplet_margin(&anchor, &far, &near, 1.0), 5.0);
+
+        let anchors = Tensor::from_data(vec![0.0f64; 4], &[2, 2], CpuBackend::new());
+        let pos = Tensor::from_data(vec![1.0, 0.0, 3.0, 4.0], &[2, 2], CpuBackend::new());
+        let neg = Tensor::from_data(vec![3.0, 4.0, 1.0, 0.0], &[2, 2], CpuBackend::new());
+        assert_eq!(triplet_margin(&anchors, &pos, &neg, 1.0), 2.5);
+    }
+
+    #[test]
+    fn cross_entropy_against_hand_computed_loss() {
+        let logits = Tensor::from_data(
+            vec![1.0f64, 2.0, 3.0, 0.0, 0.0, 0.0],
+            &[2, 3],
+            CpuBackend::new(),
+        );
+        let targets = Tensor::from_data(vec![2, 0], &[2], CpuBackend::new());
+        let lse = (1f64.exp() + 2f64.exp() + 3f64.exp()).ln();
+        let expected = ((lse - 3.0) + 3f64.ln()) / 2.0;
+        assert!((cross_entropy(&logits, &targets) - expected).abs() < 1e-12);
+
+        // Shifting the logits must not change the loss, even far out.
+        let shifted = logits.map(|x| x + 1000.0);
+        assert!((cross_entropy(&shifted, &targets) - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn mse_and_l1_with_each_reduction() {
+        let pred = Tensor::from_data(vec![1.0f64, 2.0, 3.0, 4.0], &[2, 2], CpuBackend::new());
+        let target = Tensor::from_data(vec![1.0f64, 0.0, 4.0, 1.0], &[2, 2], CpuBackend::new());
+
+        assert_eq!(mse_loss(&pred, &target, Reduction::Mean), 3.5);
+        assert_eq!(mse_loss(&pred, &target, Reduction::Sum), 14.0);
+        let each = mse_loss_elementwise(&pred, &target);
+        assert_eq!(each.shape(), &[2, 2]);
+        assert_eq!(each.data(), &[0.0, 4.0, 1.0, 9.0]);
+
+        assert_eq!(l1_loss(&pred, &target, Reduction::Mean), 1.5);
+        assert_eq!(l1_loss(&pred, &target, Reduction::Sum), 6.0);
+        assert_eq!(
+            l1_loss_elementwise(&pred, &target).data(),
+            &[0.0, 2.0, 1.0, 3.0]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "shape mismatch")]
+    fn mse_rejects_mismatched_shapes() {
+        let a: Tensor<f32, _> = Tensor::zeros(&[2], CpuBackend::new());
+        let b: Tensor<f32, _> = Tensor::zeros(&[3], CpuBackend::new());
+        mse_loss(&a, &b, Reduction::Mean);
+    }
+
+    #[test]
+    fn kl_against_hand_computed_value() {
+        let p = Tensor::from_data(vec![0.5f64, 0.5], &[2], CpuBackend::new());
+        let q = Tensor::from_data(vec![0.25f64, 0.75], &[2], CpuBackend::new());
+        let expected = 0.5 * (2.0f64).ln() + 0.5 * (0.5f64 / 0.75).ln();
+        assert!((kl_div(&p, &q, 0).data()[0] - expected).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,184 @@
+use crate::tensor::Tensor;
+use crate::tensor::backend::Backend;
+use crate::tensor::numeric::Float;
+
+macro_rules! unary_float_op {
+    ($(#[$doc:meta])* $name:ident) => {
+        $(#[$doc])*
+        pub fn $name(&self) -> Self {
+            let data = self.backend.$name(&self.values());
+            Tensor::from_data(data, &self.shape, self.backend.clone())
+        }
+    };
+}
+
+impl<T: Float, B: Backend<T>> Tensor<T, B> {
+    unary_float_op!(
+        /// Element-wise `e^x`.
+        exp
+    );
+    unary_float_op!(
+        /// Element-wise natural logarithm.
+        ln
+    );
+    unary_float_op!(
+        /// Element-wise square root.
+        sqrt
+    );
+
+    /// Raises every element to `exponent`.
+    pub fn powf(&self, exponent: T) -> Self {
+        let data = self.backend.powf(&self.values(), exponent);
+        Tensor::from_data(data, &self.shape, self.backend.clone())
+    }
+
+    /// Mask of the NaN elements.
+    pub fn is_nan(&self) -> Tensor<bool, B> {
+        let data = self.values().iter().map(|x| x.is_nan()).collect();
+        Tensor::from_raw(data, &self.shape, self.backend.clone())
+    }
+
+    /// Mask of the elements that are positive or negative infinity.
+    pub fn is_inf(&self) -> Tensor<bool, B> {
+        let data = self.values().iter().map(|x| x.is_infinite()).collect();
+        Tensor::from_raw(data, &self.shape, self.backend.clone())
+    }
+
+    /// Replaces NaN with `nan` and the infinities with `posinf` and `neginf`,
+    /// leaving finite elements untouched.
+    pub fn nan_to_num(&self, nan: T, posinf: T, neginf: T) -> Self {
+        self.map(|x| {
+            if x.is_nan() {
+                nan
+            } else if x.is_infinite() {
+                if x > T::zero() { posinf } else { neginf }
+            } else {
+                x
+            }
+        })
+    }
+}
+
+impl<T: Float, B: Backend<T>> Tensor<T, B> {
+    /// Piecewise-linear interpolation, like numpy's `interp`: evaluates at the
+    /// points `x` the function that takes the values `fp` at the increasing
+    /// sample points `xp`.
+    ///
+    /// Queries outside `[xp[0], xp[n - 1]]` take the value at the nearest
+    /// endpoint. The output has the shape of `x`.
+    pub fn interp(x: &Self, xp: &Self, fp: &Self) -> Self {
+        assert!(
+            xp.ndim() == 1 && fp.ndim() == 1 && xp.numel() == fp.numel() && xp.numel() > 0,
+            "interp needs non-empty 1D xp and fp of equal length, got {:?} and {:?}",
+            xp.shape,
+            fp.shape
+        );
+        let (xp, fp) = (xp.values(), fp.values());
+        let last = xp.len() - 1;
+        x.map(|q| {
+            if q <= xp[0] {
+                return fp[0];
+            }
+            if q >= xp[last] {
+                return fp[last];
+            }
+            // First sample strictly greater than q; q lies in [xp[i - 1], xp[i]).
+            let i = xp.partition_point(|&p| p <= q);
+            let t = (q - xp[i - 1]) / (xp[i] - xp[i - 1]);
+            fp[i - 1] + t * (fp[i] - fp[i - 1])
+        })
+    }
+}
+
+impl<B: Backend<f32>> Tensor<f32, B> {
+    /// The sinusoidal positional encoding from "Attention Is All You Need",
+    /// shaped `[seq_len, d_model]`:
+    /// `pe[pos][2i] = sin(pos / 10000^(2i / d_model))` and
+    /// `pe[pos][2i + 1]` the matching cosine.
+    pub fn positional_encoding(seq_len: usize, d_model: usize, backend: B) -> Self {
+        let mut data = Vec::with_capacity(seq_len * d_model);
+        for pos in 0..seq_len {
+            for j in 0..d_model {
+                let rate = 10000f64.powf((j - j % 2) as f64 / d_model as f64);
+                let angle = pos as f64 / rate;
+                let value = if j % 2 == 0 { angle.sin() } else { angle.cos() };
+                data.push(value as f32);
+            }
+        }
+        Tensor::from_data(data, &[seq_len, d_model], backend)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tensor::Tensor;
+    use crate::tensor::backend::CpuBackend;
+
+    const EPS: f64 = 1e-6;
+
+    #[test]
+    fn exp_ln_sqrt_of_known_values() {
+        let zero = Tensor::from_data(vec![0.0f32], &[1], CpuBackend::new());
+        assert!((zero.exp().data()[0] - 1.0).abs() < 1e-6);
+
+        let one = Tensor::from_data(vec![1.0f64], &[1], CpuBackend::new());
+        assert!(one.ln().data()[0].abs() < EPS);
+
+        let four = Tensor::from_data(vec![4.0f64, 9.0], &[2], CpuBackend::new());
+        let roots = four.sqrt();
+        assert!((roots.data()[0] - 2.0).abs() < EPS);
+        assert!((roots.data()[1] - 3.0).abs() < EPS);
+    }
+
+    #[test]
+    fn interp_between_two_points() {
+        let xp = Tensor::from_data(vec![0.0f64, 1.0], &[2], CpuBackend::new());
+        let fp = Tensor::from_data(vec![0.0f64, 10.0], &[2], CpuBackend::new());
+        let x = Tensor::from_data(vec![0.5f64], &[1], CpuBackend::new());
+        assert_eq!(Tensor::interp(&x, &xp, &fp).data(), &[5.0]);
+    }
+
+    #[test]
+    fn interp_clamps_and_handles_uneven_spacing() {
+        let xp = Tensor::from_data(vec![0.0f64, 1.0, 3.0], &[3], CpuBackend::new());
+        let fp = Tensor::from_data(vec![1.0f64, 2.0, 6.0], &[3], CpuBackend::new());
+        let x = Tensor::from_data(vec![-1.0f64, 1.0, 2.0, 5.0], &[2, 2], CpuBackend::new());
+        let y = Tensor::interp(&x, &xp, &fp);
+        assert_eq!(y.shape(), &[2, 2]);
+        assert_eq!(y.data(), &[1.0, 2.0, 4.0, 6.0]);
+    }
+
+    #[test]
+    fn nan_to_num_cleans_up_division_by_zero() {
+        let num = Tensor::from_data(vec![1.0f32, -1.0, 0.0, 2.0], &[4], CpuBackend::new());
+        let den = Tensor::from_data(vec![0.0f32, 0.0, 0.0, 4.0], &[4], CpuBackend::new());
+        let q = &num / &den;
+        assert_eq!(q.is_inf().data(), &[true, true, false, false]);
+        assert_eq!(q.is_nan().data(), &[false, false, true, false]);
+        assert_eq!(q.nan_to_num(0.0, 1e6, -1e6).data(), &[1e6, -1e6, 0.0, 0.5]);
+    }
+
+    #[test]
+    fn positional_encoding_known_values() {
+        let pe = Tensor::positional_encoding(4, 6, CpuBackend::new());
+        assert_eq!(pe.shape(), &[4, 6]);
+        // Position 0 is sin(0) = 0 and cos(0) = 1 throughout.
+        assert_eq!(&pe.data()[..6], &[0.0, 1.0, 0.0, 1.0, 0.0, 1.0]);
+        // The first pair has rate 1: sin(pos) and cos(pos).
+        assert!((pe.get(&[1, 0]) - 1f32.sin()).abs() < 1e-6);
+        assert!((pe.get(&[3, 1]) - 3f32.cos()).abs() < 1e-6);
+        // The second pair has rate 10000^(2/6) = 21.544...
+        assert!((pe.get(&[2, 2]) - (2.0 / 21.544347f32).sin()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn powf_and_inverse_ops() {
+        let t = Tensor::from_data(vec![1.0f64, 2.0, 3.0], &[3], CpuBackend::new());
+        assert!(t.powf(2.0).allclose(
+            &Tensor::from_data(vec![1.0, 4.0, 9.0], &[3], CpuBackend::new()),
+            EPS,
+            0.0
+        ));
+        assert!(t.exp().ln().allclose(&t, EPS, 0.0));
+    }
+}
@@ -0,0 +1,16 @@
+mod activation;
+mod cast;
+mod compare;
+mod conv;
+mod einsum;
+mod elementwise;
+mod linalg;
+pub mod loss;
+mod math;
+mod reduce;
+mod sample;
+mod scan;
+mod shape;
+mod sort;
+
+pub use compare::where_;
@@ -0,0 +1,638 @@
+use crate::tensor::Tensor;
+use crate::tensor::backend::Backend;
+use crate::tensor::numeric::{CastFrom, Float, Numeric};
+
+impl<T: Numeric, B: Backend<T>> Tensor<T, B> {
+    /// Reduces each lane along `axis` with `f`, dropping that axis, or the
+    /// whole tensor into a `[1]` tensor when `axis` is `None`.
+    pub(crate) fn reduce_with<F>(&self, axis: Option<usize>, f: F) -> Self
+    where
+        F: FnMut(&[T]) -> T,
+    {
+        match axis {
+            Some(axis) => {
+                let data = self.reduce_lanes(axis, f);
+                Tensor::from_data(data, &self.reduced_shape(axis), self.backend.clone())
+            }
+            None => {
+                let mut f = f;
+                Tensor::from_data(vec![f(&self.values())], &[1], self.backend.clone())
+            }
+        }
+    }
+
+    /// Sum along `axis`, or of every element into a `[1]` tensor when `axis`
+    /// is `None`.
+    pub fn sum(&self, axis: Option<usize>) -> Self {
+        self.reduce_with(axis, |lane| lane.iter().fold(T::zero(), |acc, &x| acc + x))
+    }
+
+    /// Like [`Tensor::sum`] along `axis`, but keeps that axis with size 1 so
+    /// the result lines up with `self` again.
+    pub fn sum_keepdim(&self, axis: usize) -> Self {
+        self.sum(Some(axis)).keep_axis(axis)
+    }
+
+    /// Minimum along `axis`, or over every element into a `[1]` tensor when
+    /// `axis` is `None`. Panics on an empty lane.
+    pub fn min(&self, axis: Option<usize>) -> Self
+    where
+        T: PartialOrd,
+    {
+        self.reduce_with(axis, |lane| extremum(lane, |x, best| x < best))
+    }
+
+    /// Maximum along `axis`, or over every element into a `[1]` tensor when
+    /// `axis` is `None`. Panics on an empty lane.
+    pub fn max(&self, axis: Option<usize>) -> Self
+    where
+        T: PartialOrd,
+    {
+        self.reduce_with(axis, |lane| extremum(lane, |x, best| x > best))
+    }
+
+    /// [`Tensor::min`] along `axis`, keeping it with size 1.
+    pub fn min_keepdim(&self, axis: usize) -> Self
+    where
+        T: PartialOrd,
+    {
+        self.min(Some(axis)).keep_axis(axis)
+    }
+
+    /// [`Tensor::max`] along `axis`, keeping it with size 1.
+    pub fn max_keepdim(&self, axis: usize) -> Self
+    where
+        T: PartialOrd,
+    {
+        self.max(Some(axis)).keep_axis(axis)
+    }
+
+    /// Reinserts `axis` with size 1 into a tensor reduced along it.
+    fn keep_axis(self, axis: usize) -> Self {
+        let mut shape = self.shape.clone();
+        shape.insert(axis, 1);
+        Tensor::from_data(self.data, &shape, self.backend)
+    }
+
+    /// Folds every element, in logical order, into a single value.
+    pub fn fold<F: Fn(T, T) -> T>(&self, init: T, f: F) -> T {
+        self.values().iter().fold(init, |acc, &x| f(acc, x))
+    }
+
+    /// Like [`Tensor::fold`], but splits large tensors across threads.
+    ///
+    /// `f` must be associative and `identity` neutral for it (e.g. `0` for
+    /// `+`, `1` for `*`), since it seeds each thread's partial fold.
+    pub fn par_fold<F>(&self, identity: T, f: F) -> T
+    where
+        F: Fn(T, T) -> T + Sync,
+    {
+        self.backend.fold(&self.values(), identity, f)
+    }
+
+    /// Folds each lane along `axis` starting from `init`, dropping that axis.
+    pub fn reduce<F: Fn(T, T) -> T>(&self, axis: usize, init: T, f: F) -> Self {
+        self.reduce_with(Some(axis), |lane| {
+            lane.iter().fold(init, |acc, &x| f(acc, x))
+        })
+    }
+
+    /// Whether `f` holds for at least one element; stops at the first match.
+    pub fn any<F: Fn(T) -> bool>(&self, f: F) -> bool {
+        self.values().iter().any(|&x| f(x))
+    }
+
+    /// Whether `f` holds for every element; stops at the first failure.
+    pub fn all<F: Fn(T) -> bool>(&self, f: F) -> bool {
+        self.values().iter().all(|&x| f(x))
+    }
+
+    /// Occurrences of each value `0..=max` in a tensor of non-negative
+    /// integers; entry `i` of the result counts the elements equal to `i`.
+    ///
+    /// Only integer element types qualify. Panics if the bins for the largest
+    /// value can't be allocated.
+    pub fn bincount(&self) -> Tensor<usize, B>
+    where
+        T: PartialOrd,
+        usize: TryFrom<T>,
+    {
+        let values = self.values();
+        let bin = |x: T| {
+            assert!(
+                x >= T::zero(),
+                "bincount needs non-negative values, got {x:?}"
+            );
+            usize::try_from(x)
+                .unwrap_or_else(|_| panic!("bincount value {x:?} doesn't fit in usize"))
+        };
+
+        let len = values
+            .iter()
+            .map(|&x| bin(x))
+            .max()
+            .map_or(0, |max| max.saturating_add(1));
+        let mut counts: Vec<usize> = Vec::new();
+        counts
+            .try_reserve_exact(len)
+            .unwrap_or_else(|_| panic!("bincount can't allocate {len} bins"));
+        counts.resize(len, 0);
+        for &x in values.iter() {
+            counts[bin(x)] += 1;
+        }
+        Tensor::from_raw(counts, &[len], self.backend.clone())
+    }
+
+    /// Maximum along `axis` together with its position, found in one pass.
+    ///
+    /// Both outputs have `axis` removed. Ties resolve to the first maximum.
+    pub fn max_with_index(&self, axis: usize) -> (Tensor<T, B>, Tensor<usize, B>)
+    where
+        T: PartialOrd,
+        B: Backend<usize>,
+    {
+        assert!(
+            self.shape.get(axis).is_some_and(|&n| n > 0),
+            "max_with_index needs a non-empty axis {axis} in shape {:?}",
+            self.shape
+        );
+        let (values, indices): (Vec<T>, Vec<usize>) = self
+            .reduce_lanes(axis, |lane| {
+                lane.iter()
+                    .enumerate()
+                    .skip(1)
+                    .fold(
+                        (lane[0], 0),
+                        |(best, at), (i, &x)| {
+                            if x > best { (x, i) } else { (best, at) }
+                        },
+                    )
+            })
+            .into_iter()
+            .unzip();
+
+        let shape = self.reduced_shape(axis);
+        (
+            Tensor::from_data(values, &shape, self.backend.clone()),
+            Tensor::from_data(indices, &shape, self.backend.clone()),
+        )
+    }
+}
+
+impl<T: Float, B: Backend<T>> Tensor<T, B> {
+    /// Counts of the elements falling into each of `bins` equal-width bins
+    /// spanning `range = (lo, hi)`.
+    ///
+    /// Bins are half-open `[edge, next_edge)` except the last, which also
+    /// takes `hi`. Elements outside the range, and NaN, are dropped rather
+    /// than clamped into the edge bins.
+    pub fn histogram(&self, bins: usize, range: (T, T)) -> Tensor<usize, B>
+    where
+        T: CastFrom<usize>,
+        usize: CastFrom<T>,
+    {
+        let (lo, hi) = range;
+        assert!(bins > 0, "histogram needs at least one bin");
+        assert!(
+            lo < hi,
+            "histogram range must satisfy lo < hi, got {range:?}"
+        );
+        let scale = T::cast_from(bins) / (hi - lo);
+        let mut counts = vec![0; bins];
+        for &x in self.values().iter() {
+            if x >= lo && x <= hi {
+                let bin = usize::cast_from((x - lo) * scale).min(bins - 1);
+                counts[bin] += 1;
+            }
+        }
+        Tensor::from_raw(counts, &[bins], self.backend.clone())
+    }
+
+    pub fn any_nan(&self) -> bool {
+        self.any(T::is_nan)
+    }
+
+    pub fn any_inf(&self) -> bool {
+        self.any(T::is_infinite)
+    }
+
+    /// Sum that skips NaN elements, along `axis` or over everything.
+    pub fn nansum(&self, axis: Option<usize>) -> Self {
+        self.reduce_with(axis, |lane| {
+            lane.iter()
+                .filter(|x| !x.is_nan())
+                .fold(T::zero(), |acc, &x| acc + x)
+        })
+    }
+
+    /// Mean of the non-NaN elements, along `axis` or over everything. A lane
+    /// with no valid elements yields NaN.
+    pub fn nanmean(&self, axis: Option<usize>) -> Self
+    where
+        T: CastFrom<usize>,
+    {
+        self.reduce_with(axis, |lane| {
+            let (sum, count) = lane
+                .iter()
+                .filter(|x| !x.is_nan())
+                .fold((T::zero(), 0), |(acc, n), &x| (acc + x, n + 1));
+            sum / T::cast_from(count)
+        })
+    }
+
+    /// Mean along `axis`, or of every element into a `[1]` tensor when `axis`
+    /// is `None`.
+    pub fn mean(&self, axis: Option<usize>) -> Self
+    where
+        T: CastFrom<usize>,
+    {
+        self.reduce_with(axis, lane_mean)
+    }
+
+    /// [`Tensor::mean`] along `axis`, keeping it with size 1, e.g. to center
+    /// each row with `x - x.mean_keepdim(1)`.
+    pub fn mean_keepdim(&self, axis: usize) -> Self
+    where
+        T: CastFrom<usize>,
+    {
+        self.mean(Some(axis)).keep_axis(axis)
+    }
+
+    /// Variance along `axis` or over everything. `unbiased` divides by
+    /// `n - 1` (the sample variance) instead of `n`. An empty lane gives
+    /// NaN, as does a single element with `unbiased`.
+    ///
+    /// Uses two passes, the mean and then the squared deviations from it,
+    /// which stays accurate where `E[x²] - E[x]²` would cancel away the
+    /// significant digits.
+    pub fn var(&self, axis: Option<usize>, unbiased: bool) -> Self
+    where
+        T: CastFrom<usize>,
+    {
+        self.reduce_with(axis, |lane| {
+            let mean = lane_mean(lane);
+            let squares = lane.iter().fold(T::zero(), |acc, &x| {
+                let d = x - mean;
+                d.mul_add(d, acc)
+            });
+            squares / T::cast_from(lane.len().saturating_sub(usize::from(unbiased)))
+        })
+    }
+
+    /// Standard deviation, the square root of [`Tensor::var`].
+    pub fn std(&self, axis: Option<usize>, unbiased: bool) -> Self
+    where
+        T: CastFrom<usize>,
+    {
+        self.var(axis, unbiased).sqrt()
+    }
+
+    /// Shannon entropy `-sum(p * ln p)` along `axis`, in nats. Zero
+    /// probabilities contribute nothing.
+    pub fn entropy(&self, axis: usize) -> Self {
+        self.reduce_with(Some(axis), |lane| {
+            lane.iter()
+                .filter(|&&p| p != T::zero())
+                .fold(T::zero(), |acc, &p| acc - p * p.ln())
+        })
+    }
+
+    /// Integral along `axis` by the trapezoidal rule, for samples spaced
+    /// `dx` apart. Drops that axis; lanes with fewer than two samples
+    /// integrate to zero.
+    pub fn trapz(&self, dx: T, axis: usize) -> Self {
+        let half = dx / T::from(2);
+        self.reduce_with(Some(axis), |lane| {
+            lane.windows(2)
+                .fold(T::zero(), |acc, w| (w[0] + w[1]).mul_add(half, acc))
+        })
+    }
+
+    /// Like [`Tensor::trapz`], but for samples taken at the possibly
+    /// non-uniform coordinates `x`, a 1D tensor as long as `axis`.
+    pub fn trapz_x(&self, x: &Self, axis: usize) -> Self {
+        let x = x.values();
+        assert!(
+            self.shape.get(axis) == Some(&x.len()),
+            "trapz_x needs {} coordinates along axis {axis} of shape {:?}",
+            x.len(),
+            self.shape
+        );
+        let half = T::one() / T::from(2);
+        self.reduce_with(Some(axis), |lane| {
+            lane.windows(2)
+                .zip(x.windows(2))
+                .fold(T::zero(), |acc, (y, x)| {
+                    ((y[0] + y[1]) * half).mul_add(x[1] - x[0], acc)
+                })
+        })
+    }
+
+    /// `ln(sum(exp(x)))` over every element, computed by factoring out the
+    /// maximum so large inputs do not overflow. An empty tensor yields `-inf`.
+    pub fn logsumexp_all(&self) -> T {
+        let values = self.values();
+        let Some((&first, rest)) = values.split_first() else {
+            return T::zero().ln();
+        };
+        let max = rest.iter().fold(first, |m, &x| if x > m { x } else { m });
+        // Infinite or NaN maxima make `x - max` meaningless; they are also
+        // the answer.
+        if !max.is_finite() {
+            return max;
+        }
+        let sum = values
+            .iter()
+            .fold(T::zero(), |acc, &x| acc + (x - max).exp());
+        max + sum.ln()
+    }
+}
+
+/// First element of `lane` not beaten by any later one, where
+/// `beats(x, best)` says `x` should replace the current `best`.
+fn extremum<T: Copy>(lane: &[T], beats: impl Fn(T, T) -> bool) -> T {
+    let (&first, rest) = lane
+        .split_first()
+        .expect("cannot take the extremum of an empty lane");
+    rest.iter()
+        .fold(first, |best, &x| if beats(x, best) { x } else { best })
+}
+
+fn lane_mean<T: Float + CastFrom<usize>>(lane: &[T]) -> T {
+    lane.iter().fold(T::zero(), |acc, &x| acc + x) / T::cast_from(lane.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tensor::Tensor;
+    use crate::tensor::backend::CpuBackend;
+
+    #[test]
+    fn max_with_index_along_each_axis() {
+        let t = Tensor::from_data(
+            vec![1.0, 7.0, 3.0, 9.0, 2.0, 5.0],
+            &[2, 3],
+            CpuBackend::new(),
+        );
+
+        let (values, indices) = t.max_with_index(1);
+        assert_eq!(values.shape(), &[2]);
+        assert_eq!(values.data(), &[7.0, 9.0]);
+        assert_eq!(indices.data(), &[1, 0]);
+
+        let (values, indices) = t.max_with_index(0);
+        assert_eq!(values.shape(), &[3]);
+        assert_eq!(values.data(), &[9.0, 7.0, 5.0]);
+        assert_eq!(indices.data(), &[1, 0, 1]);
+    }
+
+    #[test]
+    fn fold_computes_a_product() {
+        let t = Tensor::from_data(vec![1.5, 2.0, -3.0, 4.0], &[2, 2], CpuBackend::new());
+        assert_eq!(t.fold(1.0, |a, b| a * b), 1.5 * 2.0 * -3.0 * 4.0);
+        assert_eq!(t.par_fold(1.0, |a, b| a * b), -36.0);
+        assert_eq!(t.reduce(1, 1.0, |a, b| a * b).data(), &[3.0, -12.0]);
+    }
+
+    #[test]
+    fn par_fold_matches_fold_on_large_input() {
+        let t = Tensor::from_data((0..100_000u64).collect(), &[100_000], CpuBackend::new());
+        let add = |a: u64, b: u64| a + b;
+        assert_eq!(t.par_fold(0, add), t.fold(0, add));
+        assert_eq!(t.par_fold(0, add), 99_999 * 100_000 / 2);
+    }
+
+    #[test]
+    fn any_and_all_predicates() {
+        let mut t = Tensor::from_data(vec![0.5, 2.0, 3.0], &[3], CpuBackend::new());
+        assert!(t.all(|x| x > 0.0));
+        assert!(!t.any(|x| x < 0.0));
+        t.set(&[1], -1.0);
+        assert!(!t.all(|x| x > 0.0));
+        assert!(t.any(|x| x < 0.0));
+
+        assert!(!t.any_nan() && !t.any_inf());
+        t.set(&[0], f64::NAN);
+        t.set(&[2], f64::NEG_INFINITY);
+        assert!(t.any_nan() && t.any_inf());
+    }
+
+    #[test]
+    fn histogram_into_four_bins() {
+        let t = Tensor::from_data(
+            vec![0.0, 0.1, 0.3, 0.5, 0.55, 0.6, 0.99, 1.0, -0.2, 1.5],
+            &[10],
+            CpuBackend::new(),
+        );
+        let h = t.histogram(4, (0.0, 1.0));
+        assert_eq!(h.shape(), &[4]);
+        // [0, .25): 0, .1  [.25, .5): .3  [.5, .75): .5, .55, .6  [.75, 1]: .99, 1
+        assert_eq!(h.data(), &[2, 1, 3, 2]);
+    }
+
+    #[test]
+    fn bincount_counts_each_value() {
+        let t = Tensor::from_data(vec![1, 3, 1, 0, 3, 3], &[2, 3], CpuBackend::new());
+        assert_eq!(t.bincount().data(), &[1, 2, 0, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "non-negative")]
+    fn bincount_rejects_negative_values() {
+        Tensor::from_data(vec![1i32, -1], &[2], CpuBackend::new()).bincount();
+    }
+
+    #[test]
+    #[should_panic(expected = "bincount can't allocate")]
+    fn bincount_rejects_values_too_large_to_bin() {
+        Tensor::from_data(vec![0, i64::MAX], &[2], CpuBackend::new()).bincount();
+    }
+
+    #[test]
+    fn sum_along_axes_and_overall() {
+        let t = Tensor::from_data(vec![1, 2, 3, 4, 5, 6], &[2, 3], CpuBackend::new());
+        assert_eq!(t.sum(Some(0)).data(), &[5, 7, 9]);
+        assert_eq!(t.sum(Some(1)).data(), &[6, 15]);
+        let total = t.sum(None);
+        assert_eq!(total.shape(), &[1]);
+        assert_eq!(total.data(), &[21]);
+    }
+
+    #[test]
+    fn nanmean_ignores_nans() {
+        let t = Tensor::from_data(vec![1.0, f64::NAN, 3.0], &[3], CpuBackend::new());
+        let mean = t.nanmean(None);
+        assert_eq!(mean.shape(), &[1]);
+        assert_eq!(mean.data(), &[2.0]);
+        assert_eq!(t.nansum(None).data(), &[4.0]);
+    }
+
+    #[test]
+    fn nan_reductions_along_axis() {
+        let t = Tensor::from_data(
+            vec![1.0f32, f32::NAN, f32::NAN, f32::NAN, 4.0, 6.0],
+            &[2, 3],
+            CpuBackend::new(),
+        );
+        assert_eq!(t.nansum(Some(1)).data(), &[1.0, 10.0]);
+        assert_eq!(t.nanmean(Some(1)).data(), &[1.0, 5.0]);
+
+        let col = t.nanmean(Some(0));
+        assert_eq!(col.shape(), &[3]);
+        assert_eq!(col.data(), &[1.0, 4.0, 6.0]);
+
+        let all_nan = Tensor::from_data(vec![f32::NAN; 2], &[2], CpuBackend::new());
+        assert!(all_nan.nanmean(None).data()[0].is_nan());
+    }
+
+    #[test]
+    fn mean_var_and_std() {
+        let t = Tensor::from_data(
+            vec![1.0, 2.0, 3.0, 5.0, 5.0, 5.0],
+            &[2, 3],
+            CpuBackend::new(),
+        );
+        assert_eq!(t.mean(Some(1)).data(), &[2.0, 5.0]);
+        assert_eq!(t.var(Some(1), false).data(), &[2.0 / 3.0, 0.0]);
+        assert_eq!(t.std(Some(0), false).data(), &[2.0, 1.5, 1.0]);
+        assert_eq!(t.mean(None).data(), &[3.5]);
+    }
+
+    #[test]
+    fn biased_and_unbiased_variance() {
+        // mean 5, squared deviations 9 + 1 + 1 + 9 = 20
+        let t = Tensor::from_data(vec![2.0, 4.0, 6.0, 8.0], &[4], CpuBackend::new());
+        assert_eq!(t.var(None, false).data(), &[5.0]);
+        assert_eq!(t.var(None, true).data(), &[20.0 / 3.0]);
+        assert_eq!(t.std(None, false).data(), &[5f64.sqrt()]);
+        assert_eq!(t.std(None, true).data(), &[(20.0f64 / 3.0).sqrt()]);
+    }
+
+    #[test]
+    fn variance_of_empty_lanes_is_nan() {
+        let t = Tensor::<f64, _>::zeros(&[2, 0], CpuBackend::new());
+        for unbiased in [false, true] {
+            let v = t.var(Some(1), unbiased);
+            assert_eq!(v.shape(), &[2]);
+            assert!(v.data().iter().all(|x| x.is_nan()));
+        }
+    }
+
+    #[test]
+    fn var_is_stable_for_large_offsets() {
+        let data: Vec<f64> = [4.0, 7.0, 13.0, 16.0].iter().map(|d| 1e9 + d).collect();
+        let t = Tensor::from_data(data.clone(), &[4], CpuBackend::new());
+        assert_eq!(t.var(None, false).data(), &[22.5]);
+
+        // The one-pass formula loses everything to cancellation here.
+        let n = data.len() as f64;
+        let mean = data.iter().sum::<f64>() / n;
+        let naive = data.iter().map(|x| x * x).sum::<f64>() / n - mean * mean;
+        assert!((naive - 22.5).abs() > 1.0);
+    }
+
+    #[test]
+    fn entropy_of_uniform_is_ln_n() {
+        let t = Tensor::from_data(vec![0.25; 8], &[2, 4], CpuBackend::new());
+        let h = t.entropy(1);
+        assert_eq!(h.shape(), &[2]);
+        for &x in h.data() {
+            assert!((x - 4f64.ln()).abs() < 1e-12);
+        }
+
+        let certain = Tensor::from_data(vec![0.0, 1.0, 0.0], &[3], CpuBackend::new());
+        assert_eq!(certain.entropy(0).data(), &[0.0]);
+    }
+
+    #[test]
+    fn logsumexp_all_matches_naive_and_is_stable() {
+        let t = Tensor::from_data(vec![0.5, -1.0, 2.0, 0.0], &[2, 2], CpuBackend::new());
+        let naive = t.data().iter().map(|x: &f64| x.exp()).sum::<f64>().ln();
+        assert!((t.logsumexp_all() - naive).abs() < 1e-12);
+
+        // exp(1000) overflows f64, but the shifted form does not.
+        let big = Tensor::from_data(vec![1000.0, 1000.0], &[2], CpuBackend::new());
+        assert!((big.logsumexp_all() - (1000.0 + 2f64.ln())).abs() < 1e-9);
+
+        let empty: Tensor<f64, _> = Tensor::zeros(&[0], CpuBackend::new());
+        assert_eq!(empty.logsumexp_all(), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn max_with_index_prefers_first_tie() {
+        let t = Tensor::from_data(vec![4, 4, 1], &[3], CpuBackend::new());
+        let (values, indices) = t.max_with_index(0);
+        assert_eq!(values.shape(), &[] as &[usize]);
+        assert_eq!(values.data(), &[4]);
+        assert_eq!(indices.data(), &[0]);
+    }
+
+    #[test]
+    fn trapz_integrates_a_linear_ramp() {
+        // y = 2x sampled on [0, 3] every 0.5: the area is 9.
+        let y = Tensor::from_fn(&[7], |i| i[0] as f64, CpuBackend::new());
+        let area = y.trapz(0.5, 0);
+        assert_eq!(area.shape(), &[] as &[usize]);
+        assert!((area.data()[0] - 9.0).abs() < 1e-12);
+
+        let rows = Tensor::from_data(
+            vec![0.0, 1.0, 2.0, 1.0, 1.0, 1.0],
+            &[2, 3],
+            CpuBackend::new(),
+        );
+        assert_eq!(rows.trapz(1.0, 1).data(), &[2.0, 2.0]);
+    }
+
+    #[test]
+    fn trapz_x_handles_uneven_spacing() {
+        let x = Tensor::from_data(vec![0.0f64, 0.5, 2.0, 3.0], &[4], CpuBackend::new());
+        let y = x.map(|x| 2.0 * x);
+        assert!((y.trapz_x(&x, 0).data()[0] - 9.0).abs() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "trapz_x needs 2 coordinates")]
+    fn trapz_x_rejects_mismatched_coordinates() {
+        let y: Tensor<f64, _> = Tensor::zeros(&[3], CpuBackend::new());
+        let x: Tensor<f64, _> = Tensor::zeros(&[2], CpuBackend::new());
+        y.trapz_x(&x, 0);
+    }
+
+    #[test]
+    fn keepdim_reductions_keep_a_unit_axis() {
+        let t = Tensor::from_data(
+            vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0],
+            &[2, 3],
+            CpuBackend::new(),
+        );
+        assert_eq!(t.sum(Some(1)).shape(), &[2]);
+        assert_eq!(t.sum_keepdim(1).shape(), &[2, 1]);
+        assert_eq!(t.sum_keepdim(1).data(), &[9.0, 12.0]);
+        assert_eq!(t.sum_keepdim(0).shape(), &[1, 3]);
+
+        assert_eq!(t.max(Some(0)).data(), &[4.0, 5.0, 6.0]);
+        assert_eq!(t.max_keepdim(0).shape(), &[1, 3]);
+        assert_eq!(t.min(Some(1)).data(), &[1.0, 2.0]);
+        assert_eq!(t.min_keepdim(1).shape(), &[2, 1]);
+        assert_eq!(t.max(None).data(), &[6.0]);
+    }
+
+    #[test]
+    fn mean_keepdim_centers_rows() {
+        let x = Tensor::from_data(
+            vec![1.0, 2.0, 3.0, 4.0, 6.0, 8.0],
+            &[2, 3],
+            CpuBackend::new(),
+        );
+        assert_eq!(x.mean_keepdim(1).shape(), &[2, 1]);
+        let centered = &x - &x.mean_keepdim(1);
+        assert_eq!(centered.data(), &[-1.0, 0.0, 1.0, -2.0, 0.0, 2.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "empty lane")]
+    fn max_rejects_empty_lanes() {
+        let t: Tensor<f32, _> = Tensor::zeros(&[2, 0], CpuBackend::new());
+        t.max(Some(1));
+    }
+}
@@ -0,0 +1,125 @@
+use crate::tensor::Tensor;
+use crate::tensor::backend::Backend;
+use crate::tensor::numeric::{CastFrom, Float};
+
+impl<T, B> Tensor<T, B>
+where
+    T: Float + CastFrom<usize>,
+    i64: CastFrom<T>,
+    B: Backend<T>,
+{
+    /// Samples a `[channels, h, w]` input at the points of a
+    /// `[h_out, w_out, 2]` grid with bilinear interpolation, giving
+    /// `[channels, h_out, w_out]`.
+    ///
+    /// Each grid entry is an `(x, y)` pair normalized to `[-1, 1]`, where
+    /// `-1` and `1` are the centers of the first and last pixels along the
+    /// width and height. Taps that fall outside the input read as zero.
+    pub fn grid_sample(&self, grid: &Self) -> Self {
+        assert_eq!(
+            self.ndim(),
+            3,
+            "grid_sample expects a [channels, h, w] input, got {:?}",
+            self.shape
+        );
+        assert!(
+            grid.ndim() == 3 && grid.shape[2] == 2,
+            "grid_sample expects an [h_out, w_out, 2] grid, got {:?}",
+            grid.shape
+        );
+        let (channels, h, w) = (self.shape[0], self.shape[1], self.shape[2]);
+        let (h_out, w_out) = (grid.shape[0], grid.shape[1]);
+        let input = self.values();
+        let grid = grid.values();
+
+        let two = T::from(2);
+        // Maps a normalized coordinate onto pixel units along an axis of `len`.
+        let unnormalize = |c: T, len: usize| (c + T::one()) / two * T::cast_from(len.max(1) - 1);
+
+        let mut out = vec![T::zero(); channels * h_out * w_out];
+        for (p, point) in grid.chunks(2).enumerate() {
+            let x = unnormalize(point[0], w);
+            let y = unnormalize(point[1], h);
+            let (x0, y0) = (x.floor(), y.floor());
+            let (fx, fy) = (x - x0, y - y0);
+            // The casts saturate for huge coordinates, so step to the next
+            // tap without overflowing; such taps are padding either way.
+            let (x0, y0) = (i64::cast_from(x0), i64::cast_from(y0));
+            let (x1, y1) = (x0.saturating_add(1), y0.saturating_add(1));
+            let taps = [
+                (y0, x0, (T::one() - fy) * (T::one() - fx)),
+                (y0, x1, (T::one() - fy) * fx),
+                (y1, x0, fy * (T::one() - fx)),
+                (y1, x1, fy * fx),
+            ];
+            for (yi, xi, weight) in taps {
+                if yi < 0 || xi < 0 || yi as usize >= h || xi as usize >= w {
+                    continue;
+                }
+                let pixel = yi as usize * w + xi as usize;
+                for c in 0..channels {
+                    let v = input[c * h * w + pixel];
+                    let slot = &mut out[c * h_out * w_out + p];
+                    *slot = v.mul_add(weight, *slot);
+                }
+            }
+        }
+        Tensor::from_data(out, &[channels, h_out, w_out], self.backend.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tensor::Tensor;
+    use crate::tensor::backend::CpuBackend;
+
+    fn identity_grid(h: usize, w: usize) -> Tensor<f64, CpuBackend> {
+        let coord = |i: usize, n: usize| -1.0 + 2.0 * i as f64 / (n - 1) as f64;
+        let mut data = Vec::new();
+        for y in 0..h {
+            for x in 0..w {
+                data.extend([coord(x, w), coord(y, h)]);
+            }
+        }
+        Tensor::from_data(data, &[h, w, 2], CpuBackend::new())
+    }
+
+    #[test]
+    fn identity_grid_returns_the_input() {
+        let input = Tensor::from_data(
+            (0..24).map(f64::from).collect(),
+            &[2, 3, 4],
+            CpuBackend::new(),
+        );
+        let out = input.grid_sample(&identity_grid(3, 4));
+        assert_eq!(out.shape(), &[2, 3, 4]);
+        assert!(out.allclose(&input, 1e-12, 0.0));
+    }
+
+    #[test]
+    fn samples_between_pixels_and_zero_pads_outside() {
+        let input = Tensor::from_data(vec![0.0, 2.0, 4.0, 6.0], &[1, 2, 2], CpuBackend::new());
+        // The center, half a pixel past the top-right pixel (blending it with
+        // the zero padding), and a point entirely outside.
+        let grid = Tensor::from_data(
+            vec![0.0, 0.0, 2.0, -1.0, 5.0, 0.0],
+            &[1, 3, 2],
+            CpuBackend::new(),
+        );
+        let out = input.grid_sample(&grid);
+        assert_eq!(out.shape(), &[1, 1, 3]);
+        assert_eq!(out.data(), &[3.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn huge_coordinates_read_as_padding() {
+        let input = Tensor::from_data(vec![1.0, 2.0, 3.0, 4.0], &[1, 2, 2], CpuBackend::new());
+        let grid = Tensor::from_data(
+            vec![1e30, 0.0, 0.0, 1e30, -1e30, -1e30, 1e300, 1e300],
+            &[1, 4, 2],
+            CpuBackend::new(),
+        );
+        let out = input.grid_sample(&grid);
+        assert_eq!(out.data(), &[0.0; 4]);
+    }
+}
@@ -0,0 +1,78 @@
+use crate::tensor::Tensor;
+use crate::tensor::backend::Backend;
+use crate::tensor::numeric::Numeric;
+
+impl<T: Numeric, B: Backend<T>> Tensor<T, B> {
+    /// Running sums along `axis`: `out[i] = x[0] + x[1] + ... + x[i]`.
+    pub fn cumsum(&self, axis: usize) -> Self {
+        self.scan(axis, T::zero(), |acc, x| acc + x)
+    }
+
+    /// Running products along `axis`: `out[i] = x[0] * x[1] * ... * x[i]`.
+    pub fn cumprod(&self, axis: usize) -> Self {
+        self.scan(axis, T::one(), |acc, x| acc * x)
+    }
+
+    fn scan(&self, axis: usize, init: T, f: impl Fn(T, T) -> T) -> Self {
+        self.map_lanes(axis, |lane| {
+            let mut acc = init;
+            for x in lane.iter_mut() {
+                acc = f(acc, *x);
+                *x = acc;
+            }
+        })
+    }
+
+    /// Running sums along `axis`, accumulated from the last element towards
+    /// the first: `out[i] = x[i] + x[i + 1] + ... + x[n - 1]`.
+    pub fn cumsum_reverse(&self, axis: usize) -> Self {
+        self.map_lanes(axis, |lane| {
+            let mut acc = T::zero();
+            for x in lane.iter_mut().rev() {
+                acc = acc + *x;
+                *x = acc;
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tensor::Tensor;
+    use crate::tensor::backend::CpuBackend;
+
+    #[test]
+    fn cumsum_and_cumprod_1d() {
+        let t = Tensor::from_data(vec![1, 2, 3, 4], &[4], CpuBackend::new());
+        assert_eq!(t.cumsum(0).data(), &[1, 3, 6, 10]);
+        assert_eq!(t.cumprod(0).data(), &[1, 2, 6, 24]);
+    }
+
+    #[test]
+    fn cumsum_along_each_axis() {
+        let t = Tensor::from_data(vec![1, 2, 3, 4, 5, 6], &[2, 3], CpuBackend::new());
+        assert_eq!(t.cumsum(1).data(), &[1, 3, 6, 4, 9, 15]);
+        assert_eq!(t.cumsum(0).data(), &[1, 2, 3, 5, 7, 9]);
+        assert_eq!(t.cumprod(0).data(), &[1, 2, 3, 4, 10, 18]);
+    }
+
+    #[test]
+    fn cumsum_reverse_1d() {
+        let t = Tensor::from_data(vec![1, 2, 3, 4], &[4], CpuBackend::new());
+        assert_eq!(t.cumsum_reverse(0).data(), &[10, 9, 7, 4]);
+    }
+
+    #[test]
+    fn cumsum_reverse_along_rows_and_columns() {
+        let t = Tensor::from_data(
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+            &[2, 3],
+            CpuBackend::new(),
+        );
+        assert_eq!(
+            t.cumsum_reverse(1).data(),
+            &[6.0, 5.0, 3.0, 15.0, 11.0, 6.0]
+        );
+        assert_eq!(t.cumsum_reverse(0).data(), &[5.0, 7.0, 9.0, 4.0, 5.0, 6.0]);
+    }
+}
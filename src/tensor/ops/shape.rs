@@ -0,0 +1,707 @@
+use crate::tensor::backend::Backend;
+use crate::tensor::numeric::Numeric;
+use crate::tensor::{Layout, Tensor, compute_strides, for_each_index};
+
+impl<T: Numeric, B: Backend<T>> Tensor<T, B> {
+    /// Reorders the axes so that output axis `i` is input axis `axes[i]`.
+    ///
+    /// Only shape and strides change: the buffer is cloned but not reordered.
+    pub fn permute(&self, axes: &[usize]) -> Self {
+        assert_eq!(
+            axes.len(),
+            self.ndim(),
+            "permute expects {} axes, got {:?}",
+            self.ndim(),
+            axes
+        );
+        let mut seen = vec![false; self.ndim()];
+        for &axis in axes {
+            assert!(
+                axis < self.ndim() && !seen[axis],
+                "invalid permutation {axes:?}"
+            );
+            seen[axis] = true;
+        }
+
+        Tensor {
+            data: self.data.clone(),
+            shape: axes.iter().map(|&a| self.shape[a]).collect(),
+            strides: axes.iter().map(|&a| self.strides[a]).collect(),
+            layout: self.layout,
+            backend: self.backend.clone(),
+        }
+    }
+
+    /// Returns a row-major tensor with the same elements and a new shape.
+    ///
+    /// Panics if the element counts differ.
+    pub fn reshape(&self, shape: &[usize]) -> Self {
+        assert_eq!(
+            shape.iter().product::<usize>(),
+            self.numel(),
+            "cannot reshape {:?} into {:?}",
+            self.shape,
+            shape
+        );
+        Tensor::from_data(self.values().into_owned(), shape, self.backend.clone())
+    }
+
+    /// Swaps the last two axes.
+    pub fn transpose(&self) -> Self {
+        let n = self.ndim();
+        assert!(n >= 2, "transpose needs at least 2 dimensions, got {n}");
+        let mut axes: Vec<usize> = (0..n).collect();
+        axes.swap(n - 2, n - 1);
+        self.permute(&axes)
+    }
+
+    /// Exchanges axes `a` and `b`, leaving the others in place; a view like
+    /// [`Tensor::permute`].
+    pub fn swap_axes(&self, a: usize, b: usize) -> Self {
+        for axis in [a, b] {
+            assert!(
+                axis < self.ndim(),
+                "axis {axis} out of range for shape {:?}",
+                self.shape
+            );
+        }
+        let mut axes: Vec<usize> = (0..self.ndim()).collect();
+        axes.swap(a, b);
+        self.permute(&axes)
+    }
+
+    /// Pads every dimension with `value`: `pad_widths[i] = (before, after)`
+    /// elements are added around dimension `i`.
+    pub fn pad(&self, pad_widths: &[(usize, usize)], value: T) -> Self {
+        assert_eq!(
+            pad_widths.len(),
+            self.ndim(),
+            "pad expects one (before, after) pair per dimension of {:?}",
+            self.shape
+        );
+        let shape: Vec<usize> = self
+            .shape
+            .iter()
+            .zip(pad_widths)
+            .map(|(&dim, &(before, after))| before + dim + after)
+            .collect();
+        let out_strides = compute_strides(&shape, Layout::RowMajor);
+        let mut data = vec![value; shape.iter().product()];
+
+        let src = self.values();
+        let mut i = 0;
+        for_each_index(&self.shape, |index| {
+            let offset: usize = index
+                .iter()
+                .zip(pad_widths)
+                .zip(&out_strides)
+                .map(|((&x, &(before, _)), &stride)| (x + before) * stride)
+                .sum();
+            data[offset] = src[i];
+            i += 1;
+        });
+        Tensor::from_data(data, &shape, self.backend.clone())
+    }
+
+    /// Contiguous copy of the elements `start..start + len` along `axis`.
+    pub(crate) fn slice_axis(&self, axis: usize, start: usize, len: usize) -> Self {
+        assert!(
+            axis < self.ndim() && start + len <= self.shape[axis],
+            "range {start}..{} out of bounds for axis {axis} of {:?}",
+            start + len,
+            self.shape
+        );
+        let mut shape = self.shape.clone();
+        shape[axis] = len;
+        let mut data = Vec::with_capacity(shape.iter().product());
+        let mut src = vec![0; self.ndim()];
+        for_each_index(&shape, |index| {
+            src.copy_from_slice(index);
+            src[axis] += start;
+            data.push(self.get(&src));
+        });
+        Tensor::from_data(data, &shape, self.backend.clone())
+    }
+
+    /// The window `start..start + len` along `axis`, e.g. a span of time
+    /// steps, as a contiguous copy. Panics if the window runs past the end
+    /// of the axis.
+    pub fn narrow(&self, axis: usize, start: usize, len: usize) -> Self {
+        self.slice_axis(axis, start, len)
+    }
+
+    /// Splits along `axis` into pieces of the given sizes, which must add up
+    /// to the length of that axis.
+    pub fn split(&self, sizes: &[usize], axis: usize) -> Vec<Self> {
+        assert!(
+            axis < self.ndim(),
+            "axis {axis} out of range for shape {:?}",
+            self.shape
+        );
+        assert_eq!(
+            sizes.iter().sum::<usize>(),
+            self.shape[axis],
+            "split sizes {sizes:?} don't add up to axis {axis} of {:?}",
+            self.shape
+        );
+        let mut start = 0;
+        sizes
+            .iter()
+            .map(|&len| {
+                let piece = self.slice_axis(axis, start, len);
+                start += len;
+                piece
+            })
+            .collect()
+    }
+
+    /// Splits along `axis` into `chunks` pieces of `ceil(len / chunks)`
+    /// elements; the last piece may be smaller, and fewer pieces are returned
+    /// if the axis is too short to fill them all.
+    pub fn chunk(&self, chunks: usize, axis: usize) -> Vec<Self> {
+        assert!(chunks > 0, "chunk count must be positive");
+        assert!(
+            axis < self.ndim(),
+            "axis {axis} out of range for shape {:?}",
+            self.shape
+        );
+        let len = self.shape[axis];
+        let size = len.div_ceil(chunks).max(1);
+        let sizes: Vec<usize> = (0..len)
+            .step_by(size)
+            .map(|start| size.min(len - start))
+            .collect();
+        self.split(&sizes, axis)
+    }
+
+    /// Tiles the tensor `repeats[i]` times along each dimension `i`,
+    /// materializing the copies (unlike broadcasting).
+    pub fn repeat(&self, repeats: &[usize]) -> Self {
+        assert_eq!(
+            repeats.len(),
+            self.ndim(),
+            "repeat expects one count per dimension of {:?}",
+            self.shape
+        );
+        let shape: Vec<usize> = self.shape.iter().zip(repeats).map(|(d, r)| d * r).collect();
+        let mut data = Vec::with_capacity(shape.iter().product());
+        let mut src = vec![0; self.ndim()];
+        for_each_index(&shape, |index| {
+            for ((s, &i), &dim) in src.iter_mut().zip(index).zip(&self.shape) {
+                *s = i % dim;
+            }
+            data.push(self.get(&src));
+        });
+        Tensor::from_data(data, &shape, self.backend.clone())
+    }
+
+    /// Pads each tensor along its first axis to the longest one, then stacks
+    /// them along a new leading batch axis.
+    ///
+    /// All tensors must agree on every axis but the first. Panics on an empty
+    /// slice, since there is no backend to build the result with.
+    pub fn pad_stack(tensors: &[&Tensor<T, B>], pad_value: T) -> Tensor<T, B> {
+        let first = tensors
+            .first()
+            .expect("pad_stack needs at least one tensor");
+        assert!(
+            first.ndim() > 0,
+            "pad_stack needs tensors with at least one axis"
+        );
+        let trailing = &first.shape[1..];
+        for t in tensors {
+            assert!(
+                t.ndim() == first.ndim() && &t.shape[1..] == trailing,
+                "pad_stack shapes differ beyond the first axis: {:?} vs {:?}",
+                first.shape,
+                t.shape
+            );
+        }
+
+        let longest = tensors.iter().map(|t| t.shape[0]).max().unwrap_or(0);
+        let mut data =
+            Vec::with_capacity(tensors.len() * longest * trailing.iter().product::<usize>());
+        for t in tensors {
+            let mut widths = vec![(0, 0); t.ndim()];
+            widths[0] = (0, longest - t.shape[0]);
+            data.extend_from_slice(t.pad(&widths, pad_value).data());
+        }
+
+        let mut shape = vec![tensors.len(), longest];
+        shape.extend_from_slice(trailing);
+        Tensor::from_data(data, &shape, first.backend.clone())
+    }
+
+    /// Splits `[batch, seq, d_model]` into `[batch, heads, seq, d_model /
+    /// heads]` for multi-head attention.
+    ///
+    /// The result is a permuted view; [`Tensor::merge_heads`] undoes it.
+    pub fn split_heads(&self, num_heads: usize) -> Self {
+        assert_eq!(
+            self.ndim(),
+            3,
+            "split_heads expects [batch, seq, d_model], got {:?}",
+            self.shape
+        );
+        let (batch, seq, d_model) = (self.shape[0], self.shape[1], self.shape[2]);
+        assert!(
+            num_heads > 0 && d_model % num_heads == 0,
+            "d_model {d_model} is not divisible by {num_heads} heads"
+        );
+        self.reshape(&[batch, seq, num_heads, d_model / num_heads])
+            .permute(&[0, 2, 1, 3])
+    }
+
+    /// Joins `[batch, heads, seq, d_head]` back into `[batch, seq, heads *
+    /// d_head]`.
+    pub fn merge_heads(&self) -> Self {
+        assert_eq!(
+            self.ndim(),
+            4,
+            "merge_heads expects [batch, heads, seq, d_head], got {:?}",
+            self.shape
+        );
+        let (batch, heads, seq, d_head) =
+            (self.shape[0], self.shape[1], self.shape[2], self.shape[3]);
+        self.permute(&[0, 2, 1, 3])
+            .reshape(&[batch, seq, heads * d_head])
+    }
+
+    /// Keeps every `factor`-th element along the last axis, starting at 0.
+    ///
+    /// Meant for quick previews of large tensors; the last dimension becomes
+    /// `ceil(len / factor)`.
+    pub fn downsample(&self, factor: usize) -> Tensor<T, B> {
+        assert!(factor > 0, "downsample factor must be positive");
+        assert!(self.ndim() > 0, "cannot downsample a 0-dimensional tensor");
+
+        let last = self.shape[self.ndim() - 1];
+        let kept = last.div_ceil(factor);
+        let data: Vec<T> = self
+            .values()
+            .chunks(last.max(1))
+            .flat_map(|row| row.iter().step_by(factor).copied())
+            .collect();
+
+        let mut shape = self.shape.clone();
+        shape[self.ndim() - 1] = kept;
+        Tensor::from_data(data, &shape, self.backend.clone())
+    }
+
+    /// Cyclically shifts elements along `axis` by `shift` positions, so
+    /// `out[(i + shift) mod n] = x[i]`; negative shifts move towards the
+    /// start.
+    pub fn roll(&self, shift: isize, axis: usize) -> Self {
+        assert!(
+            axis < self.ndim(),
+            "axis {axis} out of range for shape {:?}",
+            self.shape
+        );
+        let n = self.shape[axis];
+        if n == 0 {
+            return self.contiguous();
+        }
+        let shift = shift.rem_euclid(n as isize) as usize;
+        self.map_lanes(axis, |lane| lane.rotate_right(shift))
+    }
+
+    /// Reverses the order of elements along each of `axes`.
+    pub fn flip(&self, axes: &[usize]) -> Self {
+        let mut flipped = vec![false; self.ndim()];
+        for &axis in axes {
+            assert!(
+                axis < self.ndim(),
+                "axis {axis} out of range for shape {:?}",
+                self.shape
+            );
+            flipped[axis] = true;
+        }
+        let mut data = Vec::with_capacity(self.numel());
+        let mut src = vec![0; self.ndim()];
+        for_each_index(&self.shape, |index| {
+            for (d, &i) in index.iter().enumerate() {
+                src[d] = if flipped[d] { self.shape[d] - 1 - i } else { i };
+            }
+            data.push(self.get(&src));
+        });
+        Tensor::from_data(data, &self.shape, self.backend.clone())
+    }
+
+    /// One-hot encodes integer class labels, appending an axis of length
+    /// `num_classes` that is `1` at each label and `0` elsewhere.
+    pub fn one_hot(indices: &Tensor<usize, B>, num_classes: usize, backend: B) -> Self {
+        let labels = indices.values();
+        if let Some(&bad) = labels.iter().find(|&&i| i >= num_classes) {
+            panic!("label {bad} out of range for {num_classes} classes");
+        }
+        let mut data = vec![T::zero(); labels.len() * num_classes];
+        for (row, &label) in labels.iter().enumerate() {
+            data[row * num_classes + label] = T::one();
+        }
+        let mut shape = indices.shape().to_vec();
+        shape.push(num_classes);
+        Tensor::from_data(data, &shape, backend)
+    }
+
+    /// Selects positions along `axis` by the 1D tensor `indices`, in order;
+    /// `axis` of the output has `indices.numel()` entries.
+    ///
+    /// Gathering rows of an embedding table is `table.gather(0, &ids)`.
+    pub fn gather(&self, axis: usize, indices: &Tensor<usize, B>) -> Self {
+        assert!(
+            axis < self.ndim(),
+            "axis {axis} out of range for shape {:?}",
+            self.shape
+        );
+        assert_eq!(
+            indices.ndim(),
+            1,
+            "gather expects 1D indices, got {:?}",
+            indices.shape()
+        );
+        let indices = indices.values();
+        if let Some(&bad) = indices.iter().find(|&&i| i >= self.shape[axis]) {
+            panic!(
+                "index {bad} out of bounds for axis {axis} of {:?}",
+                self.shape
+            );
+        }
+
+        let mut shape = self.shape.clone();
+        shape[axis] = indices.len();
+        let mut data = Vec::with_capacity(shape.iter().product());
+        let mut src = vec![0; self.ndim()];
+        for_each_index(&shape, |index| {
+            src.copy_from_slice(index);
+            src[axis] = indices[index[axis]];
+            data.push(self.get(&src));
+        });
+        Tensor::from_data(data, &shape, self.backend.clone())
+    }
+
+    /// The inverse of [`Tensor::gather`]: a copy of `self` where position
+    /// `indices[i]` along `axis` is overwritten with slice `i` of `src`.
+    ///
+    /// `src` matches `self` except that `axis` has `indices.numel()` entries.
+    /// When an index repeats, the slice that comes last in `indices` wins.
+    pub fn scatter(&self, axis: usize, indices: &Tensor<usize, B>, src: &Self) -> Self {
+        assert!(
+            axis < self.ndim(),
+            "axis {axis} out of range for shape {:?}",
+            self.shape
+        );
+        assert_eq!(
+            indices.ndim(),
+            1,
+            "scatter expects 1D indices, got {:?}",
+            indices.shape()
+        );
+        let indices = indices.values();
+        let mut expected = self.shape.clone();
+        expected[axis] = indices.len();
+        assert_eq!(
+            src.shape, expected,
+            "scatter source has shape {:?}, expected {expected:?}",
+            src.shape
+        );
+        if let Some(&bad) = indices.iter().find(|&&i| i >= self.shape[axis]) {
+            panic!(
+                "index {bad} out of bounds for axis {axis} of {:?}",
+                self.shape
+            );
+        }
+
+        let mut out = self.contiguous();
+        let mut dst = vec![0; self.ndim()];
+        // Row-major order visits the slices in index order, so later
+        // duplicates overwrite earlier ones.
+        for_each_index(&src.shape, |index| {
+            dst.copy_from_slice(index);
+            dst[axis] = indices[index[axis]];
+            out.set(&dst, src.get(index));
+        });
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tensor::Tensor;
+    use crate::tensor::backend::CpuBackend;
+
+    #[test]
+    fn permute_rearranges_strides() {
+        let t = Tensor::from_data((0..24).collect::<Vec<i32>>(), &[2, 3, 4], CpuBackend::new());
+        let p = t.permute(&[2, 0, 1]);
+        assert_eq!(p.shape(), &[4, 2, 3]);
+        assert_eq!(p.strides(), &[1, 12, 4]);
+        assert_eq!(p.get(&[3, 1, 2]), t.get(&[1, 2, 3]));
+    }
+
+    #[test]
+    fn reshape_keeps_logical_order() {
+        let t = Tensor::from_data(vec![1, 2, 3, 4, 5, 6], &[2, 3], CpuBackend::new());
+        let r = t.transpose().reshape(&[6]);
+        assert_eq!(r.data(), &[1, 4, 2, 5, 3, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot reshape")]
+    fn reshape_rejects_wrong_size() {
+        let t: Tensor<f32, _> = Tensor::zeros(&[2, 3], CpuBackend::new());
+        t.reshape(&[4]);
+    }
+
+    #[test]
+    fn transpose_swaps_last_axes() {
+        let t = Tensor::from_data(vec![1, 2, 3, 4, 5, 6], &[2, 3], CpuBackend::new());
+        let tt = t.transpose();
+        assert_eq!(tt.shape(), &[3, 2]);
+        assert_eq!(tt.values().to_vec(), vec![1, 4, 2, 5, 3, 6]);
+    }
+
+    #[test]
+    fn swap_axes_of_a_3d_tensor() {
+        let t = Tensor::from_data((0..24).collect(), &[2, 3, 4], CpuBackend::new());
+        let s = t.swap_axes(0, 2);
+        assert_eq!(s.shape(), &[4, 3, 2]);
+        assert_eq!(s.get(&[3, 1, 0]), t.get(&[0, 1, 3]));
+        assert_eq!(s.get(&[2, 0, 1]), 14);
+        assert_eq!(t.swap_axes(1, 1), t);
+    }
+
+    #[test]
+    #[should_panic(expected = "axis 3 out of range")]
+    fn swap_axes_rejects_bad_axes() {
+        let t: Tensor<f32, _> = Tensor::zeros(&[2, 3, 4], CpuBackend::new());
+        t.swap_axes(0, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid permutation")]
+    fn permute_rejects_repeated_axes() {
+        let t: Tensor<f32, _> = Tensor::zeros(&[2, 2], CpuBackend::new());
+        t.permute(&[0, 0]);
+    }
+
+    #[test]
+    fn pad_adds_a_border() {
+        let t = Tensor::from_data(vec![1, 2, 3, 4], &[2, 2], CpuBackend::new());
+        let p = t.pad(&[(1, 1), (1, 1)], 0);
+        assert_eq!(p.shape(), &[4, 4]);
+        #[rustfmt::skip]
+        assert_eq!(p.data(), &[
+            0, 0, 0, 0,
+            0, 1, 2, 0,
+            0, 3, 4, 0,
+            0, 0, 0, 0,
+        ]);
+    }
+
+    #[test]
+    fn pad_asymmetric_with_fill_value() {
+        let t = Tensor::from_data(vec![1.0, 2.0], &[2], CpuBackend::new());
+        assert_eq!(t.pad(&[(0, 2)], -1.0).data(), &[1.0, 2.0, -1.0, -1.0]);
+    }
+
+    #[test]
+    fn chunk_into_equal_pieces() {
+        let t = Tensor::from_data((0..12).collect::<Vec<i32>>(), &[6, 2], CpuBackend::new());
+        let pieces = t.chunk(3, 0);
+        assert_eq!(pieces.len(), 3);
+        for (i, p) in pieces.iter().enumerate() {
+            assert_eq!(p.shape(), &[2, 2]);
+            let start = i as i32 * 4;
+            assert_eq!(p.data(), &[start, start + 1, start + 2, start + 3]);
+        }
+    }
+
+    #[test]
+    fn chunk_with_uneven_remainder() {
+        let t = Tensor::from_data((0..5).collect::<Vec<i32>>(), &[5], CpuBackend::new());
+        let sizes: Vec<usize> = t.chunk(2, 0).iter().map(|p| p.shape()[0]).collect();
+        assert_eq!(sizes, vec![3, 2]);
+    }
+
+    #[test]
+    fn narrow_selects_a_row_window() {
+        let t = Tensor::from_data((0..15).collect(), &[5, 3], CpuBackend::new());
+        let rows = t.narrow(0, 1, 3);
+        assert_eq!(rows.shape(), &[3, 3]);
+        assert_eq!(rows.data(), &[3, 4, 5, 6, 7, 8, 9, 10, 11]);
+        assert_eq!(t.narrow(1, 2, 1).data(), &[2, 5, 8, 11, 14]);
+    }
+
+    #[test]
+    #[should_panic(expected = "range 3..6 out of bounds for axis 0")]
+    fn narrow_rejects_windows_past_the_end() {
+        let t: Tensor<f32, _> = Tensor::zeros(&[5, 3], CpuBackend::new());
+        t.narrow(0, 3, 3);
+    }
+
+    #[test]
+    fn split_by_sizes() {
+        let t = Tensor::from_data((0..12).collect::<Vec<i32>>(), &[6, 2], CpuBackend::new());
+        let pieces = t.split(&[1, 2, 3], 0);
+        assert_eq!(pieces[0].data(), &[0, 1]);
+        assert_eq!(pieces[1].shape(), &[2, 2]);
+        assert_eq!(pieces[1].data(), &[2, 3, 4, 5]);
+        assert_eq!(pieces[2].shape(), &[3, 2]);
+        assert_eq!(pieces[2].data(), &[6, 7, 8, 9, 10, 11]);
+
+        let cols = t.split(&[1, 1], 1);
+        assert_eq!(cols[1].data(), &[1, 3, 5, 7, 9, 11]);
+    }
+
+    #[test]
+    #[should_panic(expected = "don't add up")]
+    fn split_sizes_must_cover_axis() {
+        let t: Tensor<f32, _> = Tensor::zeros(&[6, 2], CpuBackend::new());
+        t.split(&[1, 2], 0);
+    }
+
+    #[test]
+    fn repeat_tiles_along_each_axis() {
+        let t = Tensor::from_data(vec![1, 2], &[1, 2], CpuBackend::new());
+        let r = t.repeat(&[3, 2]);
+        assert_eq!(r.shape(), &[3, 4]);
+        assert_eq!(r.data(), &[1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2]);
+
+        let col = Tensor::from_data(vec![1, 2], &[2, 1], CpuBackend::new());
+        assert_eq!(
+            col.repeat(&[2, 3]).data(),
+            &[1, 1, 1, 2, 2, 2, 1, 1, 1, 2, 2, 2]
+        );
+    }
+
+    #[test]
+    fn pad_stack_pads_to_longest() {
+        let short = Tensor::from_data(vec![1.0, 2.0], &[2], CpuBackend::new());
+        let long = Tensor::from_data(vec![3.0, 4.0, 5.0, 6.0], &[4], CpuBackend::new());
+        let batch = Tensor::pad_stack(&[&short, &long], 0.0);
+        assert_eq!(batch.shape(), &[2, 4]);
+        assert_eq!(batch.data(), &[1.0, 2.0, 0.0, 0.0, 3.0, 4.0, 5.0, 6.0]);
+    }
+
+    #[test]
+    fn pad_stack_keeps_trailing_axes() {
+        let a = Tensor::from_data(vec![1, 2], &[1, 2], CpuBackend::new());
+        let b = Tensor::from_data(vec![3, 4, 5, 6], &[2, 2], CpuBackend::new());
+        let batch = Tensor::pad_stack(&[&a, &b], -1);
+        assert_eq!(batch.shape(), &[2, 2, 2]);
+        assert_eq!(batch.data(), &[1, 2, -1, -1, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn roll_wraps_in_both_directions() {
+        let t = Tensor::from_data(vec![0, 1, 2, 3], &[4], CpuBackend::new());
+        assert_eq!(t.roll(1, 0).data(), &[3, 0, 1, 2]);
+        assert_eq!(t.roll(-1, 0).data(), &[1, 2, 3, 0]);
+        assert_eq!(t.roll(9, 0).data(), &[3, 0, 1, 2]);
+
+        let m = Tensor::from_data((0..6).collect(), &[2, 3], CpuBackend::new());
+        assert_eq!(m.roll(1, 1).data(), &[2, 0, 1, 5, 3, 4]);
+        assert_eq!(m.roll(1, 0).data(), &[3, 4, 5, 0, 1, 2]);
+    }
+
+    #[test]
+    fn flip_along_one_and_both_axes() {
+        let t = Tensor::from_data((0..6).collect(), &[2, 3], CpuBackend::new());
+        assert_eq!(t.flip(&[1]).data(), &[2, 1, 0, 5, 4, 3]);
+        assert_eq!(t.flip(&[0, 1]).data(), &[5, 4, 3, 2, 1, 0]);
+        assert_eq!(t.flip(&[]), t);
+    }
+
+    #[test]
+    fn one_hot_labels() {
+        let labels = Tensor::from_data(vec![0, 2, 1], &[3], CpuBackend::new());
+        let encoded: Tensor<f32, _> = Tensor::one_hot(&labels, 3, CpuBackend::new());
+        assert_eq!(encoded.shape(), &[3, 3]);
+        assert_eq!(
+            encoded.data(),
+            &[1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "label 3 out of range")]
+    fn one_hot_rejects_out_of_range_labels() {
+        let labels = Tensor::from_data(vec![3], &[1], CpuBackend::new());
+        let _: Tensor<f32, _> = Tensor::one_hot(&labels, 3, CpuBackend::new());
+    }
+
+    #[test]
+    fn gather_rows_in_index_order() {
+        let t = Tensor::from_data((0..12).collect(), &[3, 4], CpuBackend::new());
+        let ids = Tensor::from_data(vec![2, 0], &[2], CpuBackend::new());
+        let rows = t.gather(0, &ids);
+        assert_eq!(rows.shape(), &[2, 4]);
+        assert_eq!(rows.data(), &[8, 9, 10, 11, 0, 1, 2, 3]);
+
+        let cols = t.gather(
+            1,
+            &Tensor::from_data(vec![3, 3, 1], &[3], CpuBackend::new()),
+        );
+        assert_eq!(cols.shape(), &[3, 3]);
+        assert_eq!(cols.data(), &[3, 3, 1, 7, 7, 5, 11, 11, 9]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 3 out of bounds")]
+    fn gather_rejects_out_of_bounds_indices() {
+        let t: Tensor<f32, _> = Tensor::zeros(&[3, 4], CpuBackend::new());
+        t.gather(0, &Tensor::from_data(vec![1, 3], &[2], CpuBackend::new()));
+    }
+
+    #[test]
+    fn scatter_writes_rows_at_indices() {
+        let base: Tensor<i32, _> = Tensor::zeros(&[4, 2], CpuBackend::new());
+        let ids = Tensor::from_data(vec![3, 1], &[2], CpuBackend::new());
+        let src = Tensor::from_data(vec![1, 2, 3, 4], &[2, 2], CpuBackend::new());
+        let out = base.scatter(0, &ids, &src);
+        assert_eq!(out.data(), &[0, 0, 3, 4, 0, 0, 1, 2]);
+        assert_eq!(out.gather(0, &ids), src);
+    }
+
+    #[test]
+    fn scatter_duplicates_keep_the_last_write() {
+        let base: Tensor<i32, _> = Tensor::zeros(&[3], CpuBackend::new());
+        let ids = Tensor::from_data(vec![1, 1], &[2], CpuBackend::new());
+        let src = Tensor::from_data(vec![5, 9], &[2], CpuBackend::new());
+        assert_eq!(base.scatter(0, &ids, &src).data(), &[0, 9, 0]);
+    }
+
+    #[test]
+    fn split_heads_round_trips_through_merge_heads() {
+        let x = Tensor::from_data((0..24).collect(), &[2, 2, 6], CpuBackend::new());
+        let heads = x.split_heads(3);
+        assert_eq!(heads.shape(), &[2, 3, 2, 2]);
+        // Head 1 of batch 0 holds features 2..4 of both positions.
+        assert_eq!(heads.get(&[0, 1, 0, 0]), 2);
+        assert_eq!(heads.get(&[0, 1, 1, 1]), 9);
+        assert_eq!(heads.merge_heads(), x);
+    }
+
+    #[test]
+    #[should_panic(expected = "not divisible")]
+    fn split_heads_rejects_uneven_split() {
+        let x: Tensor<f32, _> = Tensor::zeros(&[1, 2, 6], CpuBackend::new());
+        x.split_heads(4);
+    }
+
+    #[test]
+    fn downsample_picks_even_indices() {
+        let t = Tensor::from_data((0..8).map(|x| x as f32).collect(), &[8], CpuBackend::new());
+        let d = t.downsample(2);
+        assert_eq!(d.shape(), &[4]);
+        assert_eq!(d.data(), &[0.0, 2.0, 4.0, 6.0]);
+    }
+
+    #[test]
+    fn downsample_works_per_row() {
+        let t = Tensor::from_data((0..10).collect::<Vec<i32>>(), &[2, 5], CpuBackend::new());
+        let d = t.downsample(2);
+        assert_eq!(d.shape(), &[2, 3]);
+        assert_eq!(d.data(), &[0, 2, 4, 5, 7, 9]);
+    }
+}
@@ -0,0 +1,105 @@
+use std::cmp::Ordering;
+
+use crate::tensor::Tensor;
+use crate::tensor::backend::Backend;
+use crate::tensor::numeric::Numeric;
+
+impl<T: Numeric + PartialOrd, B: Backend<T> + Backend<usize>> Tensor<T, B> {
+    /// Sorts every lane along `axis`, returning the sorted values and, for
+    /// each output position, the index along `axis` it was taken from.
+    ///
+    /// The sort is stable, so equal elements keep their relative order.
+    /// Unordered pairs (anything involving NaN) compare as equal.
+    pub fn sort(&self, axis: usize, descending: bool) -> (Self, Tensor<usize, B>) {
+        let n = self.shape.get(axis).copied().unwrap_or(0);
+        self.sorted_prefix(axis, descending, n)
+    }
+
+    /// The `k` largest values along `axis`, in descending order, and their
+    /// indices along `axis`.
+    pub fn topk(&self, k: usize, axis: usize) -> (Self, Tensor<usize, B>) {
+        assert!(
+            self.shape.get(axis).is_some_and(|&n| k <= n),
+            "topk needs k <= size of axis {axis}, got k = {k} for shape {:?}",
+            self.shape
+        );
+        self.sorted_prefix(axis, true, k)
+    }
+
+    /// First `k` entries of each sorted lane along `axis`, which shrinks to
+    /// length `k` in the outputs.
+    fn sorted_prefix(&self, axis: usize, descending: bool, k: usize) -> (Self, Tensor<usize, B>) {
+        let (bases, step) = self.lane_bases(axis);
+        let n = self.shape[axis];
+        let data = self.values();
+        let mut shape = self.shape.clone();
+        shape[axis] = k;
+        let len: usize = shape.iter().product();
+        let mut values = vec![T::zero(); len];
+        let mut indices = vec![0; len];
+
+        let mut order: Vec<usize> = Vec::with_capacity(n);
+        for (lane, base) in bases.into_iter().enumerate() {
+            order.clear();
+            order.extend(0..n);
+            order.sort_by(|&i, &j| {
+                let (a, b) = (data[base + i * step], data[base + j * step]);
+                let ord = a.partial_cmp(&b).unwrap_or(Ordering::Equal);
+                if descending { ord.reverse() } else { ord }
+            });
+            // Lanes come outer index first, then inner, as in the output.
+            let out_base = lane / step * k * step + lane % step;
+            for (r, &i) in order[..k].iter().enumerate() {
+                values[out_base + r * step] = data[base + i * step];
+                indices[out_base + r * step] = i;
+            }
+        }
+
+        (
+            Tensor::from_data(values, &shape, self.backend.clone()),
+            Tensor::from_data(indices, &shape, self.backend.clone()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tensor::Tensor;
+    use crate::tensor::backend::CpuBackend;
+
+    #[test]
+    fn sort_ascending_with_permutation() {
+        let t = Tensor::from_data(vec![3, 1, 2], &[3], CpuBackend::new());
+        let (values, indices) = t.sort(0, false);
+        assert_eq!(values.data(), &[1, 2, 3]);
+        assert_eq!(indices.data(), &[1, 2, 0]);
+    }
+
+    #[test]
+    fn sort_descending_along_columns() {
+        let t = Tensor::from_data(
+            vec![1.0, 5.0, 3.0, 2.0, 4.0, 0.0],
+            &[3, 2],
+            CpuBackend::new(),
+        );
+        let (values, indices) = t.sort(0, true);
+        assert_eq!(values.data(), &[4.0, 5.0, 3.0, 2.0, 1.0, 0.0]);
+        assert_eq!(indices.data(), &[2, 0, 1, 1, 0, 2]);
+    }
+
+    #[test]
+    fn topk_picks_largest_per_row() {
+        let t = Tensor::from_data(vec![3, 1, 2, 7, 9, 8], &[2, 3], CpuBackend::new());
+        let (values, indices) = t.topk(2, 1);
+        assert_eq!(values.shape(), &[2, 2]);
+        assert_eq!(values.data(), &[3, 2, 9, 8]);
+        assert_eq!(indices.data(), &[0, 2, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "topk needs k <= size of axis 0")]
+    fn topk_rejects_k_beyond_axis() {
+        let t = Tensor::from_data(vec![1, 2], &[2], CpuBackend::new());
+        t.topk(3, 0);
+    }
+}
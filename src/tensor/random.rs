@@ -0,0 +1,116 @@
+/// Small seedable pseudo-random generator (xoshiro256**), for reproducible
+/// weight initialization and shuffling.
+///
+/// Fast and statistically sound for those uses, but not cryptographically
+/// secure.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: [u64; 4],
+}
+
+impl Rng {
+    /// A generator whose whole output stream is determined by `seed`.
+    pub fn seed_from_u64(seed: u64) -> Self {
+        // Expand the seed with SplitMix64, which never yields the all-zero
+        // state xoshiro can't leave.
+        let mut x = seed;
+        let mut next = || {
+            x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = x;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        };
+        Self {
+            state: [next(), next(), next(), next()],
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+
+    /// Uniform in `[0, 1)`, with 53 random bits.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Uniform in `[low, high)`.
+    pub fn uniform(&mut self, low: f64, high: f64) -> f64 {
+        low + (high - low) * self.next_f64()
+    }
+
+    /// Normally distributed with the given mean and standard deviation, by
+    /// the Box-Muller transform.
+    pub fn normal(&mut self, mean: f64, std: f64) -> f64 {
+        // 1 - u lies in (0, 1], keeping the logarithm finite.
+        let radius = (-2.0 * (1.0 - self.next_f64()).ln()).sqrt();
+        let angle = std::f64::consts::TAU * self.next_f64();
+        mean + std * radius * angle.cos()
+    }
+
+    /// Uniform in `0..n`. Panics if `n` is zero.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "cannot pick from an empty range");
+        // Lemire's multiply-shift; the bias for any realistic `n` is far
+        // below 2^-32.
+        ((u128::from(self.next_u64()) * n as u128) >> 64) as usize
+    }
+
+    /// Shuffles `items` in place (Fisher-Yates).
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rng;
+
+    #[test]
+    fn same_seed_same_stream() {
+        let mut a = Rng::seed_from_u64(7);
+        let mut b = Rng::seed_from_u64(7);
+        let mut c = Rng::seed_from_u64(8);
+        let xs: Vec<u64> = (0..4).map(|_| a.next_u64()).collect();
+        assert_eq!(xs, (0..4).map(|_| b.next_u64()).collect::<Vec<_>>());
+        assert_ne!(xs, (0..4).map(|_| c.next_u64()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn uniform_and_normal_moments() {
+        let mut rng = Rng::seed_from_u64(42);
+        let n = 100_000;
+        let u: Vec<f64> = (0..n).map(|_| rng.uniform(-1.0, 3.0)).collect();
+        assert!(u.iter().all(|x| (-1.0..3.0).contains(x)));
+        let mean = u.iter().sum::<f64>() / n as f64;
+        assert!((mean - 1.0).abs() < 0.02);
+
+        let z: Vec<f64> = (0..n).map(|_| rng.normal(2.0, 0.5)).collect();
+        let mean = z.iter().sum::<f64>() / n as f64;
+        let var = z.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n as f64;
+        assert!((mean - 2.0).abs() < 0.01);
+        assert!((var.sqrt() - 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn shuffle_is_a_permutation() {
+        let mut rng = Rng::seed_from_u64(1);
+        let mut items: Vec<usize> = (0..50).collect();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..50).collect::<Vec<_>>());
+        items.sort_unstable();
+        assert_eq!(items, (0..50).collect::<Vec<_>>());
+    }
+}
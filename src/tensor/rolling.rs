@@ -0,0 +1,97 @@
+use std::collections::VecDeque;
+
+use crate::tensor::numeric::{CastFrom, Float};
+
+/// Percentiles over the most recent `window` values of a stream, e.g. for
+/// adaptive thresholds on a training metric.
+///
+/// Keeps the window both in arrival order, to know what to evict, and
+/// sorted, so a query is a lookup. A push costs `O(window)`.
+#[derive(Debug, Clone)]
+pub struct RollingPercentile<T> {
+    window: usize,
+    recent: VecDeque<T>,
+    sorted: Vec<T>,
+}
+
+impl<T: Float> RollingPercentile<T> {
+    pub fn new(window: usize) -> Self {
+        assert!(window > 0, "rolling window must hold at least one value");
+        Self {
+            window,
+            recent: VecDeque::with_capacity(window),
+            sorted: Vec::with_capacity(window),
+        }
+    }
+
+    /// Adds `value`, evicting the oldest value once the window is full.
+    /// Panics on NaN, which has no place in the ordering.
+    pub fn push(&mut self, value: T) {
+        assert!(!value.is_nan(), "cannot track NaN in a RollingPercentile");
+        if self.recent.len() == self.window {
+            let oldest = self.recent.pop_front().unwrap();
+            let at = self.position(oldest);
+            self.sorted.remove(at);
+        }
+        self.recent.push_back(value);
+        let at = self.position(value);
+        self.sorted.insert(at, value);
+    }
+
+    pub fn len(&self) -> usize {
+        self.recent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.recent.is_empty()
+    }
+
+    /// The `q`-th quantile (`q` in `[0, 1]`) of the current window,
+    /// interpolating linearly between neighbouring values like numpy's
+    /// default; `percentile(0.5)` is the median.
+    pub fn percentile(&self, q: f64) -> T
+    where
+        T: CastFrom<f64>,
+    {
+        assert!((0.0..=1.0).contains(&q), "quantile {q} outside [0, 1]");
+        assert!(!self.is_empty(), "percentile of an empty window");
+        let pos = q * (self.sorted.len() - 1) as f64;
+        let lo = pos.floor() as usize;
+        let hi = pos.ceil() as usize;
+        let (a, b) = (self.sorted[lo], self.sorted[hi]);
+        a + (b - a) * T::cast_from(pos - lo as f64)
+    }
+
+    fn position(&self, value: T) -> usize {
+        self.sorted.partition_point(|&x| x < value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn median_after_each_push() {
+        let mut p = RollingPercentile::new(3);
+        let mut medians = Vec::new();
+        for x in [5.0, 1.0, 3.0, 10.0, 2.0, 2.0] {
+            p.push(x);
+            medians.push(p.percentile(0.5));
+        }
+        // Windows: [5], [5 1], [5 1 3], [1 3 10], [3 10 2], [10 2 2]
+        assert_eq!(medians, [5.0, 3.0, 3.0, 3.0, 3.0, 2.0]);
+        assert_eq!(p.len(), 3);
+    }
+
+    #[test]
+    fn extreme_and_interpolated_quantiles() {
+        let mut p = RollingPercentile::new(8);
+        for x in [4.0f32, 1.0, 3.0, 2.0] {
+            p.push(x);
+        }
+        assert_eq!(p.percentile(0.0), 1.0);
+        assert_eq!(p.percentile(1.0), 4.0);
+        assert_eq!(p.percentile(0.25), 1.75);
+    }
+}
@@ -0,0 +1,145 @@
+use crate::tensor::{Tensor, for_each_index};
+
+/// A read-only, strided window onto another tensor's buffer, created without
+/// copying any elements.
+///
+/// Unlike [`Tensor`] views such as [`Tensor::permute`], which carry their
+/// own copy of the buffer, a `TensorView` borrows the tensor it was taken
+/// from.
+#[derive(Debug, Clone)]
+pub struct TensorView<'a, T, B> {
+    base: &'a Tensor<T, B>,
+    shape: Vec<usize>,
+    strides: Vec<usize>,
+}
+
+impl<T, B> Tensor<T, B> {
+    /// A zero-copy view broadcasting `self` to `new_shape`.
+    ///
+    /// Dimensions are matched from the trailing axis. Each must either keep
+    /// its size or be 1 in `self`, in which case its stride becomes 0 and
+    /// every index along it reads the same element; extra leading
+    /// dimensions are broadcast the same way.
+    pub fn expand(&self, new_shape: &[usize]) -> TensorView<'_, T, B> {
+        assert!(
+            new_shape.len() >= self.ndim(),
+            "cannot expand {:?} to fewer dimensions {new_shape:?}",
+            self.shape
+        );
+        let lead = new_shape.len() - self.ndim();
+        let strides = new_shape
+            .iter()
+            .enumerate()
+            .map(|(i, &size)| match i.checked_sub(lead) {
+                Some(j) if self.shape[j] == size => self.strides[j],
+                Some(j) if self.shape[j] == 1 => 0,
+                None => 0,
+                Some(j) => panic!(
+                    "cannot expand dimension {j} of size {} to {size} in {:?}",
+                    self.shape[j], self.shape
+                ),
+            })
+            .collect();
+        TensorView {
+            base: self,
+            shape: new_shape.to_vec(),
+            strides,
+        }
+    }
+}
+
+impl<'a, T, B> TensorView<'a, T, B> {
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Strides into the buffer of [`TensorView::base`], 0 along broadcast
+    /// dimensions.
+    pub fn strides(&self) -> &[usize] {
+        &self.strides
+    }
+
+    pub fn ndim(&self) -> usize {
+        self.shape.len()
+    }
+
+    pub fn numel(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    /// The tensor whose buffer this view reads.
+    pub fn base(&self) -> &'a Tensor<T, B> {
+        self.base
+    }
+}
+
+impl<T: Copy, B: Clone> TensorView<'_, T, B> {
+    /// Panics if the number of indices doesn't match the rank or any index
+    /// is out of bounds.
+    pub fn get(&self, indices: &[usize]) -> T {
+        assert_eq!(
+            indices.len(),
+            self.ndim(),
+            "expected {} indices, got {}",
+            self.ndim(),
+            indices.len()
+        );
+        let offset: usize = indices
+            .iter()
+            .zip(&self.shape)
+            .zip(&self.strides)
+            .map(|((&i, &dim), &stride)| {
+                assert!(
+                    i < dim,
+                    "index {i} out of bounds for dimension of size {dim}"
+                );
+                i * stride
+            })
+            .sum();
+        self.base.data[offset]
+    }
+
+    /// Copies the viewed elements into a new row-major tensor.
+    pub fn to_tensor(&self) -> Tensor<T, B> {
+        let mut data = Vec::with_capacity(self.numel());
+        for_each_index(&self.shape, |index| data.push(self.get(index)));
+        Tensor::from_raw(data, &self.shape, self.base.backend.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tensor::Tensor;
+    use crate::tensor::backend::CpuBackend;
+
+    #[test]
+    fn expand_a_row_without_copying() {
+        let row = Tensor::from_data(vec![1, 2, 3], &[1, 3], CpuBackend::new());
+        let view = row.expand(&[4, 3]);
+        assert_eq!(view.shape(), &[4, 3]);
+        assert_eq!(view.strides(), &[0, 1]);
+        assert!(std::ptr::eq(view.base(), &row));
+        for i in 0..4 {
+            assert_eq!(view.get(&[i, 2]), 3);
+        }
+        assert_eq!(
+            view.to_tensor().data(),
+            &[1, 2, 3, 1, 2, 3, 1, 2, 3, 1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn expand_adds_leading_dimensions() {
+        let col = Tensor::from_data(vec![1, 2], &[2, 1], CpuBackend::new());
+        let view = col.expand(&[3, 2, 2]);
+        assert_eq!(view.strides(), &[0, 1, 0]);
+        assert_eq!(view.get(&[2, 1, 0]), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot expand dimension 1 of size 3")]
+    fn expand_rejects_non_unit_dimensions() {
+        let t: Tensor<f32, _> = Tensor::zeros(&[1, 3], CpuBackend::new());
+        t.expand(&[2, 4]);
+    }
+}